@@ -0,0 +1,55 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use fasttime::Date;
+use std::hint::black_box;
+use time::{Date as TimeDate, Month};
+
+/// Every calendar day of 1990..2030, as raw components.
+fn ymd_samples() -> Vec<(i32, u8, u8)> {
+    let mut out = Vec::new();
+    let mut date = Date::from_ymd(1990, 1, 1).unwrap();
+    while date.year < 2030 {
+        out.push((date.year, date.month, date.day));
+        date = date.succ().unwrap();
+    }
+    out
+}
+
+fn bench_from_ymd(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_ymd");
+    let samples = ymd_samples();
+    let len = samples.len();
+
+    let fast_samples = samples.clone();
+    group.bench_function(format!("fasttime::from_ymd-n={len}"), move |b| {
+        b.iter(|| {
+            for &(y, m, d) in &fast_samples {
+                black_box(Date::from_ymd(black_box(y), black_box(m), black_box(d)).unwrap());
+            }
+        });
+    });
+
+    // The const constructor validates the same way but returns `Option`,
+    // so any gap here is error-classification overhead in `from_ymd`.
+    let const_samples = samples.clone();
+    group.bench_function(format!("fasttime::from_ymd_const-n={len}"), move |b| {
+        b.iter(|| {
+            for &(y, m, d) in &const_samples {
+                black_box(Date::from_ymd_const(black_box(y), black_box(m), black_box(d)).unwrap());
+            }
+        });
+    });
+
+    let time_samples = samples;
+    group.bench_function(format!("time::from_calendar_date-n={len}"), move |b| {
+        b.iter(|| {
+            for &(y, m, d) in &time_samples {
+                let month = Month::try_from(black_box(m)).unwrap();
+                black_box(TimeDate::from_calendar_date(black_box(y), month, black_box(d)).unwrap());
+            }
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_from_ymd);
+criterion_main!(benches);
@@ -109,10 +109,54 @@ fn bench_to_unix_timestamp_nanos(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_batch_unix_timestamps(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_unix_timestamps");
+    for &(len, label) in SAMPLE_SIZES {
+        let secs: Vec<i64> = unix_samples(len).into_iter().map(|(s, _)| s).collect();
+        let (dts, _) = datetime_samples(len);
+
+        let scalar_secs = secs.clone();
+        group.bench_function(format!("from-scalar::{label}-n={len}"), move |b| {
+            b.iter(|| {
+                let out: Vec<_> = scalar_secs
+                    .iter()
+                    .map(|&s| DateTime::from_unix_timestamp(s, 0))
+                    .collect();
+                black_box(out);
+            });
+        });
+
+        group.bench_function(format!("from-batch::{label}-n={len}"), move |b| {
+            b.iter(|| black_box(DateTime::from_unix_timestamps(black_box(&secs))));
+        });
+
+        let scalar_dts = dts.clone();
+        let mut scalar_out = vec![0i64; len];
+        group.bench_function(format!("to-scalar::{label}-n={len}"), move |b| {
+            b.iter(|| {
+                for (i, dt) in scalar_dts.iter().enumerate() {
+                    scalar_out[i] = dt.unix_timestamp();
+                }
+                black_box(&scalar_out);
+            });
+        });
+
+        let mut batch_out = vec![0i64; len];
+        group.bench_function(format!("to-batch::{label}-n={len}"), move |b| {
+            b.iter(|| {
+                DateTime::to_unix_timestamps(black_box(&dts), &mut batch_out);
+                black_box(&batch_out);
+            });
+        });
+    }
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_from_unix_timestamp,
     bench_to_unix_timestamp,
-    bench_to_unix_timestamp_nanos
+    bench_to_unix_timestamp_nanos,
+    bench_batch_unix_timestamps
 );
 criterion_main!(benches);
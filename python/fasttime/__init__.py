@@ -6,6 +6,7 @@ from .fasttime import *  # noqa: F403, F401
 
 __all__ = [
     "Weekday",
+    "Month",
     "Date",
     "Time",
     "Duration",
@@ -5,7 +5,8 @@ This file provides type hints for the fasttime module, which is
 implemented in Rust via PyO3.
 """
 
-from typing import ClassVar
+import datetime
+from typing import ClassVar, overload
 
 class Weekday:
     """Calendar weekday (ISO order, Monday = 1)."""
@@ -21,6 +22,55 @@ class Weekday:
         """Get the weekday number from Monday (1-7)."""
         ...
 
+    def long_name(self) -> str:
+        """Get the English name, e.g. "Monday"."""
+        ...
+
+    def short_name(self) -> str:
+        """Get the three-letter English abbreviation, e.g. "Mon"."""
+        ...
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class Month:
+    """Calendar month (January = 1)."""
+    JANUARY: ClassVar[Month]
+    FEBRUARY: ClassVar[Month]
+    MARCH: ClassVar[Month]
+    APRIL: ClassVar[Month]
+    MAY: ClassVar[Month]
+    JUNE: ClassVar[Month]
+    JULY: ClassVar[Month]
+    AUGUST: ClassVar[Month]
+    SEPTEMBER: ClassVar[Month]
+    OCTOBER: ClassVar[Month]
+    NOVEMBER: ClassVar[Month]
+    DECEMBER: ClassVar[Month]
+
+    @classmethod
+    def from_number(cls, number: int) -> Month:
+        """Create a Month from its number (1-12)."""
+        ...
+
+    def number(self) -> int:
+        """Get the month number (1-12)."""
+        ...
+
+    def long_name(self) -> str:
+        """Get the English name, e.g. "January"."""
+        ...
+
+    def short_name(self) -> str:
+        """Get the three-letter English abbreviation, e.g. "Jan"."""
+        ...
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+
 class Date:
     """Gregorian calendar date (proleptic)."""
     
@@ -55,20 +105,63 @@ class Date:
     def weekday(self) -> Weekday:
         """Get the weekday."""
         ...
+
+    def num_days_from_monday(self) -> int:
+        """Get days since the preceding Monday (0-6, Monday = 0)."""
+        ...
+
+    def num_days_from_sunday(self) -> int:
+        """Get days since the preceding Sunday (0-6, Sunday = 0)."""
+        ...
     
     def ordinal(self) -> int:
         """Get the day of the year (1-366)."""
         ...
+
+    def with_ordinal(self, ordinal: int) -> Date:
+        """Same year with the month and day of a day-of-year."""
+        ...
     
     def add_days(self, days: int) -> Date:
         """Add days to the date."""
         ...
+
+    def sub_days(self, days: int) -> Date:
+        """Subtract days from the date."""
+        ...
+
+    def years_between(self, later: Date) -> int:
+        """Completed years from this date to `later`, as for an age."""
+        ...
+
+    def clamp(self, min: Date, max: Date) -> Date:
+        """Restrict to the inclusive range min..=max; ValueError if min > max."""
+        ...
+
+    def succ(self) -> Date:
+        """Get the next calendar day."""
+        ...
+
+    def pred(self) -> Date:
+        """Get the previous calendar day."""
+        ...
     
     @classmethod
     def parse(cls, s: str) -> Date:
         """Parse a date from ISO format (YYYY-MM-DD)."""
         ...
 
+    def __str__(self) -> str: ...
+    def __format__(self, spec: str) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: Date) -> bool: ...
+    def __le__(self, other: Date) -> bool: ...
+    def __gt__(self, other: Date) -> bool: ...
+    def __ge__(self, other: Date) -> bool: ...
+    def __hash__(self) -> int: ...
+
 class Time:
     """Time of day in nanoseconds since midnight."""
     
@@ -103,12 +196,48 @@ class Time:
     def nanos_since_midnight(self) -> int:
         """Get total nanoseconds since midnight."""
         ...
+
+    def difference(self, other: Time) -> Duration:
+        """Signed difference between two times on the same day (self - other)."""
+        ...
+
+    def abs_difference(self, other: Time) -> Duration:
+        """Shortest distance between two clock times, wrapping around midnight."""
+        ...
+
+    def round_to_second(self) -> tuple[Time, bool]:
+        """Round to the nearest second, half up; the flag marks a day rollover."""
+        ...
+
+    def round_to_millis(self) -> tuple[Time, bool]:
+        """Round to the nearest millisecond, half up; the flag marks a day rollover."""
+        ...
+
+    def round_to_micros(self) -> tuple[Time, bool]:
+        """Round to the nearest microsecond, half up; the flag marks a day rollover."""
+        ...
     
+    @classmethod
+    def from_nanos_since_midnight(cls, nanos: int) -> Time:
+        """Create a Time from nanoseconds since midnight."""
+        ...
+
     @classmethod
     def parse(cls, s: str) -> Time:
         """Parse a time from ISO format."""
         ...
 
+    def __str__(self) -> str: ...
+    def __format__(self, spec: str) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: Time) -> bool: ...
+    def __le__(self, other: Time) -> bool: ...
+    def __gt__(self, other: Time) -> bool: ...
+    def __ge__(self, other: Time) -> bool: ...
+    def __hash__(self) -> int: ...
+
 class Duration:
     """Signed duration with nanosecond precision."""
     
@@ -131,6 +260,16 @@ class Duration:
     def nanoseconds(cls, ns: int) -> Duration:
         """Create a duration from nanoseconds."""
         ...
+
+    @classmethod
+    def from_seconds_f64(cls, secs: float) -> Duration:
+        """Create a duration from fractional seconds, rounded to the nearest nanosecond."""
+        ...
+
+    @classmethod
+    def from_millis_f64(cls, ms: float) -> Duration:
+        """Create a duration from fractional milliseconds, rounded to the nearest nanosecond."""
+        ...
     
     def total_seconds(self) -> float:
         """Get total seconds as a float."""
@@ -139,11 +278,29 @@ class Duration:
     def total_nanos(self) -> int:
         """Get total nanoseconds as an integer."""
         ...
+
+    def to_parts(self) -> tuple[bool, int, int, int, int, int]:
+        """Split into (negative, days, hours, minutes, seconds, nanoseconds)."""
+        ...
+
+    def to_clock_string(self, digits: int = 0) -> str:
+        """Format as a stopwatch-style "HH:MM:SS" string, e.g. "01:30:15"."""
+        ...
     
     def __add__(self, other: Duration) -> Duration: ...
     def __sub__(self, other: Duration) -> Duration: ...
     def __neg__(self) -> Duration: ...
 
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: Duration) -> bool: ...
+    def __le__(self, other: Duration) -> bool: ...
+    def __gt__(self, other: Duration) -> bool: ...
+    def __ge__(self, other: Duration) -> bool: ...
+    def __hash__(self) -> int: ...
+
 class DateTime:
     """Combined UTC date and time."""
     
@@ -160,6 +317,61 @@ class DateTime:
     def time(self) -> Time:
         """Time component."""
         ...
+
+    @property
+    def year(self) -> int:
+        """Year (i32 range)."""
+        ...
+
+    @property
+    def month(self) -> int:
+        """Month (1-12)."""
+        ...
+
+    @property
+    def day(self) -> int:
+        """Day of month (1-31)."""
+        ...
+
+    @property
+    def hour(self) -> int:
+        """Hour (0-23)."""
+        ...
+
+    @property
+    def minute(self) -> int:
+        """Minute (0-59)."""
+        ...
+
+    @property
+    def second(self) -> int:
+        """Second (0-59)."""
+        ...
+
+    @property
+    def nanosecond(self) -> int:
+        """Nanosecond (0-999,999,999)."""
+        ...
+
+    def weekday(self) -> Weekday:
+        """Get the weekday."""
+        ...
+
+    def ordinal(self) -> int:
+        """Get the day of the year (1-366)."""
+        ...
+
+    def with_date(self, date: Date) -> DateTime:
+        """Return a copy with the date replaced, keeping the time of day."""
+        ...
+
+    def with_time(self, time: Time) -> DateTime:
+        """Return a copy with the time of day replaced, keeping the date."""
+        ...
+
+    def with_nanosecond(self, nanosecond: int) -> DateTime:
+        """Return a copy with the nanosecond replaced."""
+        ...
     
     @classmethod
     def from_unix_timestamp(cls, secs: int, nanos: int = 0) -> DateTime:
@@ -173,6 +385,33 @@ class DateTime:
     def unix_timestamp_nanos(self) -> int:
         """Get Unix timestamp in nanoseconds."""
         ...
+
+    def unix_timestamp_millis(self) -> int:
+        """Get Unix timestamp in milliseconds (rounded down)."""
+        ...
+
+    def unix_timestamp_micros(self) -> int:
+        """Get Unix timestamp in microseconds (rounded down)."""
+        ...
+
+    @classmethod
+    def from_unix_timestamp_millis(cls, ms: int) -> DateTime:
+        """Create a DateTime from milliseconds since the Unix epoch."""
+        ...
+
+    @classmethod
+    def from_unix_timestamp_micros(cls, us: int) -> DateTime:
+        """Create a DateTime from microseconds since the Unix epoch."""
+        ...
+
+    @classmethod
+    def from_unix_timestamp_f64(cls, secs: float) -> DateTime:
+        """Create a DateTime from fractional seconds since the Unix epoch."""
+        ...
+
+    def unix_timestamp_f64(self) -> float:
+        """Get Unix timestamp as fractional seconds (lossy)."""
+        ...
     
     def add_duration(self, dur: Duration) -> DateTime:
         """Add a duration to this DateTime."""
@@ -181,29 +420,79 @@ class DateTime:
     def difference(self, other: DateTime) -> Duration:
         """Calculate the difference between two DateTimes."""
         ...
+
+    def clamp(self, min: DateTime, max: DateTime) -> DateTime:
+        """Restrict to the inclusive range min..=max; ValueError if min > max."""
+        ...
     
+    def __add__(self, other: Duration) -> DateTime: ...
+    def __radd__(self, other: Duration) -> DateTime: ...
+    @overload
+    def __sub__(self, other: Duration) -> DateTime: ...
+    @overload
+    def __sub__(self, other: DateTime) -> Duration: ...
+
+    def assume_offset(self, offset: UtcOffset) -> OffsetDateTime:
+        """Attach a fixed offset, treating this DateTime as the UTC instant."""
+        ...
+
+    def assume_utc(self) -> OffsetDateTime:
+        """Attach the UTC offset (+00:00)."""
+        ...
+
+    def to_pydatetime(self) -> datetime.datetime:
+        """Convert to an aware datetime.datetime in UTC (truncated to microseconds)."""
+        ...
+
+    @classmethod
+    def from_pydatetime(cls, dt: datetime.datetime) -> DateTime:
+        """Create a DateTime from a datetime.datetime (naive values are taken as UTC)."""
+        ...
+
     @classmethod
     def now_utc(cls) -> DateTime:
         """Get the current UTC DateTime."""
         ...
+
+    def elapsed(self) -> Duration:
+        """Time from this DateTime until now (negative for future instants)."""
+        ...
     
     @classmethod
     def parse(cls, s: str) -> DateTime:
         """Parse a DateTime from ISO 8601 / RFC 3339 UTC format."""
         ...
 
+    def __str__(self) -> str: ...
+    def __format__(self, spec: str) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: DateTime) -> bool: ...
+    def __le__(self, other: DateTime) -> bool: ...
+    def __gt__(self, other: DateTime) -> bool: ...
+    def __ge__(self, other: DateTime) -> bool: ...
+    def __hash__(self) -> int: ...
+
 class UtcOffset:
     """Fixed offset from UTC."""
     
+    UTC: ClassVar[UtcOffset]
+
     @classmethod
-    def from_seconds(cls, seconds: int) -> UtcOffset:
-        """Create a UtcOffset from total seconds."""
+    def from_seconds(cls, seconds: int, unknown_local: bool = False) -> UtcOffset:
+        """Create a UtcOffset from total seconds; `unknown_local` marks a zero offset as -00:00."""
         ...
     
     @classmethod
     def from_hours_minutes(cls, sign_positive: bool, hours: int, minutes: int) -> UtcOffset:
         """Create a UtcOffset from hours and minutes."""
         ...
+
+    @classmethod
+    def from_whole_hours(cls, hours: int) -> UtcOffset:
+        """Create a UtcOffset from a signed whole number of hours (-12 to 14)."""
+        ...
     
     def as_seconds(self) -> int:
         """Get the offset as seconds."""
@@ -213,6 +502,30 @@ class UtcOffset:
         """Check if this is UTC (offset = 0)."""
         ...
 
+    def is_unknown_local(self) -> bool:
+        """Check if this is the "-00:00" unknown-local-offset marker."""
+        ...
+
+    def negate(self) -> UtcOffset:
+        """Return the offset with its sign flipped (+05:30 becomes -05:30)."""
+        ...
+
+    def saturating_add(self, delta_seconds: int) -> UtcOffset:
+        """Shift by delta_seconds, clamping to +/-24 hours."""
+        ...
+
+    def __neg__(self) -> UtcOffset: ...
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: UtcOffset) -> bool: ...
+    def __le__(self, other: UtcOffset) -> bool: ...
+    def __gt__(self, other: UtcOffset) -> bool: ...
+    def __ge__(self, other: UtcOffset) -> bool: ...
+    def __hash__(self) -> int: ...
+
 class OffsetDateTime:
     """Date-time with a fixed offset from UTC."""
     
@@ -221,6 +534,11 @@ class OffsetDateTime:
         """Create an OffsetDateTime from a UTC DateTime and offset."""
         ...
     
+    @classmethod
+    def from_unix_timestamp(cls, secs: int, nanos: int, offset: UtcOffset) -> OffsetDateTime:
+        """Create an OffsetDateTime from a Unix timestamp, shown at `offset`."""
+        ...
+    
     @classmethod
     def from_local(cls, date: Date, time: Time, offset: UtcOffset) -> OffsetDateTime:
         """Create an OffsetDateTime from local date, time, and offset."""
@@ -235,7 +553,56 @@ class OffsetDateTime:
     def offset(self) -> UtcOffset:
         """Offset from UTC."""
         ...
-    
+
+    @property
+    def local_date(self) -> Date:
+        """Local date as seen in this offset."""
+        ...
+
+    @property
+    def local_time(self) -> Time:
+        """Local time of day as seen in this offset."""
+        ...
+
+    @property
+    def year(self) -> int:
+        """Local year."""
+        ...
+
+    @property
+    def month(self) -> int:
+        """Local month (1-12)."""
+        ...
+
+    @property
+    def day(self) -> int:
+        """Local day of month (1-31)."""
+        ...
+
+    @property
+    def hour(self) -> int:
+        """Local hour (0-23)."""
+        ...
+
+    @property
+    def minute(self) -> int:
+        """Local minute (0-59)."""
+        ...
+
+    @property
+    def second(self) -> int:
+        """Local second (0-59)."""
+        ...
+
+    @property
+    def nanosecond(self) -> int:
+        """Nanosecond component (0-999,999,999)."""
+        ...
+
+    def weekday(self) -> Weekday:
+        """Get the local weekday."""
+        ...
+
     def to_local(self) -> DateTime:
         """Convert to local DateTime."""
         ...
@@ -256,13 +623,35 @@ class OffsetDateTime:
         """Calculate the difference between two OffsetDateTimes."""
         ...
     
+    def is_same_local_date(self, other: OffsetDateTime) -> bool:
+        """Check whether both fall on the same local date, each in its own offset."""
+        ...
+    
+    def __add__(self, other: Duration) -> OffsetDateTime: ...
+    def __radd__(self, other: Duration) -> OffsetDateTime: ...
+    @overload
+    def __sub__(self, other: Duration) -> OffsetDateTime: ...
+    @overload
+    def __sub__(self, other: OffsetDateTime) -> Duration: ...
+
     @classmethod
     def parse(cls, s: str) -> OffsetDateTime:
         """Parse an OffsetDateTime from RFC 3339 format."""
         ...
 
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: OffsetDateTime) -> bool: ...
+    def __le__(self, other: OffsetDateTime) -> bool: ...
+    def __gt__(self, other: OffsetDateTime) -> bool: ...
+    def __ge__(self, other: OffsetDateTime) -> bool: ...
+    def __hash__(self) -> int: ...
+
 __all__ = [
     "Weekday",
+    "Month",
     "Date",
     "Time",
     "Duration",
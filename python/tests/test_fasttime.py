@@ -2,6 +2,12 @@
 Tests for the fasttime Python bindings.
 """
 
+import ast
+import copy
+import datetime
+import pathlib
+import pickle
+
 import pytest
 import fasttime
 
@@ -44,6 +50,28 @@ def test_date_weekday():
     date = fasttime.Date(2024, 1, 1)  # Was a Monday
     weekday = date.weekday()
     assert weekday.number_from_monday() == 1
+    assert weekday.long_name() == "Monday"
+    assert weekday.short_name() == "Mon"
+    assert fasttime.Weekday.SUNDAY.long_name() == "Sunday"
+    assert str(weekday) == "Monday"
+    assert date.num_days_from_monday() == 0
+    assert date.num_days_from_sunday() == 1
+    assert fasttime.Date(1969, 12, 28).num_days_from_sunday() == 0
+
+
+def test_month_names():
+    """Test month numbers and names."""
+    month = fasttime.Month.from_number(9)
+    assert month.number() == 9
+    assert month.long_name() == "September"
+    assert month.short_name() == "Sep"
+    assert fasttime.Month.JANUARY.long_name() == "January"
+    assert str(month) == "September"
+    assert month == fasttime.Month.SEPTEMBER
+    assert month != fasttime.Month.OCTOBER
+    assert {month: 1}[fasttime.Month.SEPTEMBER] == 1
+    with pytest.raises(ValueError):
+        fasttime.Month.from_number(13)
 
 
 def test_date_ordinal():
@@ -53,6 +81,10 @@ def test_date_ordinal():
     
     date = fasttime.Date(2024, 12, 31)
     assert date.ordinal() == 366  # 2024 is a leap year
+    assert date.with_ordinal(60) == fasttime.Date(2024, 2, 29)
+    assert fasttime.Date(2023, 5, 5).with_ordinal(60) == fasttime.Date(2023, 3, 1)
+    with pytest.raises(ValueError):
+        fasttime.Date(2023, 1, 1).with_ordinal(366)
 
 
 def test_date_add_days():
@@ -62,6 +94,45 @@ def test_date_add_days():
     assert next_day.year == 2024
     assert next_day.month == 1
     assert next_day.day == 2
+    assert date.sub_days(1) == fasttime.Date(2023, 12, 31)
+    assert date.sub_days(-1) == next_day
+    with pytest.raises(ValueError):
+        date.sub_days(-(2**63))
+
+
+def test_date_succ_pred():
+    """Test next/previous calendar day."""
+    date = fasttime.Date(2024, 2, 29)
+    assert date.succ() == fasttime.Date(2024, 3, 1)
+    assert date.pred() == fasttime.Date(2024, 2, 28)
+    assert fasttime.Date(2024, 1, 1).pred() == fasttime.Date(2023, 12, 31)
+
+
+def test_date_years_between():
+    """Test counting completed years."""
+    born = fasttime.Date(2000, 3, 1)
+    assert born.years_between(fasttime.Date(2023, 2, 28)) == 22
+    assert born.years_between(fasttime.Date(2023, 3, 1)) == 23
+    assert fasttime.Date(2023, 3, 1).years_between(born) == -23
+
+    leapling = fasttime.Date(2004, 2, 29)
+    assert leapling.years_between(fasttime.Date(2023, 2, 28)) == 19
+
+
+def test_clamp():
+    """Test clamping dates and datetimes to a range."""
+    lo, hi = fasttime.Date(2024, 1, 1), fasttime.Date(2024, 12, 31)
+    assert fasttime.Date(2023, 6, 1).clamp(lo, hi) == lo
+    assert fasttime.Date(2025, 6, 1).clamp(lo, hi) == hi
+    assert fasttime.Date(2024, 6, 1).clamp(lo, hi) == fasttime.Date(2024, 6, 1)
+    with pytest.raises(ValueError):
+        lo.clamp(hi, lo)
+
+    start = fasttime.DateTime.parse("2024-01-01T00:00:00Z")
+    end = fasttime.DateTime.parse("2024-01-02T00:00:00Z")
+    assert fasttime.DateTime.parse("2030-01-01T00:00:00Z").clamp(start, end) == end
+    with pytest.raises(ValueError):
+        start.clamp(end, start)
 
 
 def test_date_comparison():
@@ -109,6 +180,43 @@ def test_time_parse():
     assert time.nanosecond == 123_456_789
 
 
+def test_time_from_nanos_since_midnight():
+    """Test round-tripping nanoseconds since midnight."""
+    time = fasttime.Time(23, 59, 59, 999_999_999)
+    assert fasttime.Time.from_nanos_since_midnight(time.nanos_since_midnight()) == time
+    assert fasttime.Time.from_nanos_since_midnight(0) == fasttime.Time(0, 0, 0)
+
+    with pytest.raises(ValueError):
+        fasttime.Time.from_nanos_since_midnight(86_400 * 1_000_000_000)
+
+
+def test_time_difference():
+    """Test same-day and wrap-around time differences."""
+    late, early = fasttime.Time(23, 0, 0), fasttime.Time(1, 0, 0)
+    assert late.difference(early) == fasttime.Duration.seconds(22 * 3600)
+    assert early.difference(late) == fasttime.Duration.seconds(-22 * 3600)
+    assert late.abs_difference(early) == fasttime.Duration.seconds(2 * 3600)
+    assert early.abs_difference(late) == fasttime.Duration.seconds(2 * 3600)
+
+
+def test_time_rounding():
+    """Test rounding to coarser precision."""
+    time = fasttime.Time(12, 0, 0, 500_000_000)
+    assert time.round_to_second() == (fasttime.Time(12, 0, 1), False)
+    assert fasttime.Time(12, 0, 0, 123_456_789).round_to_millis() == (
+        fasttime.Time(12, 0, 0, 123_000_000),
+        False,
+    )
+    assert fasttime.Time(12, 0, 0, 123_456_789).round_to_micros() == (
+        fasttime.Time(12, 0, 0, 123_457_000),
+        False,
+    )
+    assert fasttime.Time(23, 59, 59, 600_000_000).round_to_second() == (
+        fasttime.Time(0, 0, 0),
+        True,
+    )
+
+
 def test_time_invalid():
     """Test that invalid times raise errors."""
     with pytest.raises(ValueError):
@@ -135,6 +243,28 @@ def test_datetime_from_timestamp():
     assert dt.time.nanosecond == 123_456_789
 
 
+def test_datetime_millis_micros():
+    """Test millisecond and microsecond Unix timestamps."""
+    dt = fasttime.DateTime.from_unix_timestamp(1_700_000_000, 123_456_789)
+    assert dt.unix_timestamp_millis() == 1_700_000_000_123
+    assert dt.unix_timestamp_micros() == 1_700_000_000_123_456
+
+    assert fasttime.DateTime.from_unix_timestamp_millis(-1) == fasttime.DateTime.from_unix_timestamp(0, -1_000_000)
+    assert fasttime.DateTime.from_unix_timestamp_micros(1_700_000_000_123_456).time.nanosecond == 123_456_000
+
+    half = fasttime.DateTime.from_unix_timestamp_f64(1_699_228_799.5)
+    assert half == fasttime.DateTime.from_unix_timestamp(1_699_228_799, 500_000_000)
+    assert half.unix_timestamp_f64() == 1_699_228_799.5
+    assert fasttime.DateTime.from_unix_timestamp_f64(-0.25) == fasttime.DateTime.from_unix_timestamp(-1, 750_000_000)
+    for bad in (float("nan"), float("inf"), 1e30):
+        with pytest.raises(ValueError):
+            fasttime.DateTime.from_unix_timestamp_f64(bad)
+
+    far = fasttime.DateTime(fasttime.Date(2_000_000_000, 1, 1), fasttime.Time(0, 0, 0))
+    with pytest.raises(OverflowError):
+        far.unix_timestamp_millis()
+
+
 def test_datetime_parse():
     """Test parsing datetimes from strings."""
     dt = fasttime.DateTime.parse("2024-06-15T12:30:45Z")
@@ -146,12 +276,61 @@ def test_datetime_parse():
     assert dt.time.second == 45
 
 
+def test_datetime_accessors():
+    """Test the flat date/time accessors."""
+    dt = fasttime.DateTime.parse("2024-03-01T07:08:09.000000042Z")
+    assert (dt.year, dt.month, dt.day) == (2024, 3, 1)
+    assert (dt.hour, dt.minute, dt.second, dt.nanosecond) == (7, 8, 9, 42)
+    assert dt.weekday().number_from_monday() == 5
+    assert dt.ordinal() == 61
+
+
+def test_datetime_with_fields():
+    """Test replacing the date, time and nanosecond."""
+    dt = fasttime.DateTime.parse("2024-03-01T07:08:09.000000042Z")
+    assert dt.with_nanosecond(0) == fasttime.DateTime.parse("2024-03-01T07:08:09Z")
+    assert dt.with_date(fasttime.Date(2020, 2, 29)) == fasttime.DateTime.parse("2020-02-29T07:08:09.000000042Z")
+    assert dt.with_time(fasttime.Time(0, 0, 0)) == fasttime.DateTime.parse("2024-03-01T00:00:00Z")
+    with pytest.raises(ValueError):
+        dt.with_nanosecond(1_000_000_000)
+
+
+def test_format_spec():
+    """Test f-string format specs on Date, Time and DateTime."""
+    dt = fasttime.DateTime.parse("2024-01-15T14:30:45.123456789Z")
+    assert f"{dt:%d/%m/%Y %H:%M}" == "15/01/2024 14:30"
+    assert f"{dt.date:%y-%j}" == "24-015"
+    assert f"{dt.time:%T.%f}" == "14:30:45.123456789"
+    assert f"{dt}" == str(dt)
+    assert format(dt.date, "") == "2024-01-15"
+    with pytest.raises(ValueError):
+        format(dt.date, "%H")
+    with pytest.raises(ValueError):
+        format(dt.time, "%Y")
+    with pytest.raises(ValueError):
+        format(dt, "100%")
+
+
+def test_datetime_parse_offset_hint():
+    """Test that an offset string points at OffsetDateTime.parse."""
+    with pytest.raises(ValueError, match="OffsetDateTime.parse"):
+        fasttime.DateTime.parse("2024-06-15T12:30:45+02:00")
+
+
 def test_datetime_now_utc():
     """Test getting current UTC time."""
     now = fasttime.DateTime.now_utc()
     assert now.date.year >= 2024  # Should be in the future
 
 
+def test_datetime_elapsed():
+    """Test the time elapsed since a DateTime."""
+    past = fasttime.DateTime.parse("2020-01-01T00:00:00Z")
+    assert past.elapsed() > fasttime.Duration.seconds(0)
+    future = fasttime.DateTime.now_utc() + fasttime.Duration.seconds(3600)
+    assert future.elapsed() < fasttime.Duration.seconds(0)
+
+
 def test_datetime_add_duration():
     """Test adding durations to datetimes."""
     dt = fasttime.DateTime.parse("2024-01-01T00:00:00Z")
@@ -170,6 +349,71 @@ def test_datetime_difference():
     assert diff.total_seconds() == 3600
 
 
+def test_datetime_operators():
+    """Test + and - on datetimes."""
+    dt = fasttime.DateTime.parse("2024-01-01T00:00:00Z")
+    hour = fasttime.Duration.seconds(3600)
+
+    later = dt + hour
+    assert later == fasttime.DateTime.parse("2024-01-01T01:00:00Z")
+    assert hour + dt == later
+    assert later - hour == dt
+    assert later - dt == hour
+
+    odt = fasttime.OffsetDateTime.parse("2024-01-01T05:30:00+05:30")
+    odt_later = odt + hour
+    assert str(odt_later) == "2024-01-01T06:30:00+05:30"
+    assert odt_later - hour == odt
+    assert odt_later - odt == hour
+
+    with pytest.raises(TypeError):
+        dt + 5
+    with pytest.raises(TypeError):
+        dt - odt
+
+
+def test_datetime_operator_overflow():
+    """Test that out-of-range arithmetic raises OverflowError."""
+    dt = fasttime.DateTime.parse("2024-01-01T00:00:00Z")
+    huge = fasttime.Duration.seconds(2**62)
+    with pytest.raises(OverflowError):
+        dt + huge
+    with pytest.raises(OverflowError):
+        dt - huge
+
+    duration_min = fasttime.Duration.nanoseconds(-(2**127))
+    with pytest.raises(OverflowError):
+        dt - duration_min
+    with pytest.raises(OverflowError):
+        dt.assume_utc() - duration_min
+
+
+def test_datetime_to_pydatetime():
+    """Test conversion to a stdlib datetime."""
+    dt = fasttime.DateTime.parse("2024-06-15T12:30:45.123456789Z")
+    py_dt = dt.to_pydatetime()
+    assert py_dt == datetime.datetime(
+        2024, 6, 15, 12, 30, 45, 123456, tzinfo=datetime.timezone.utc
+    )
+    assert py_dt.tzinfo is datetime.timezone.utc
+
+
+def test_datetime_from_pydatetime():
+    """Test conversion from stdlib datetimes."""
+    tz = datetime.timezone(datetime.timedelta(hours=5, minutes=30))
+    aware = datetime.datetime(2024, 6, 15, 18, 0, 0, 250, tzinfo=tz)
+    dt = fasttime.DateTime.from_pydatetime(aware)
+    assert dt == fasttime.DateTime.parse("2024-06-15T12:30:00.00025Z")
+
+    naive = datetime.datetime(2024, 1, 1, 0, 0, 0)
+    assert fasttime.DateTime.from_pydatetime(naive) == fasttime.DateTime.parse(
+        "2024-01-01T00:00:00Z"
+    )
+
+    original = fasttime.DateTime.parse("2024-06-15T12:30:45.123456Z")
+    assert fasttime.DateTime.from_pydatetime(original.to_pydatetime()) == original
+
+
 def test_duration_creation():
     """Test creating durations."""
     seconds = fasttime.Duration.seconds(60)
@@ -179,6 +423,18 @@ def test_duration_creation():
     assert millis.total_seconds() == 1
 
 
+def test_duration_from_floats():
+    """Test float constructors and their NaN/infinity/range guards."""
+    assert fasttime.Duration.from_seconds_f64(1.5) == fasttime.Duration.milliseconds(1500)
+    assert fasttime.Duration.from_millis_f64(-0.25) == fasttime.Duration.microseconds(-250)
+    with pytest.raises(ValueError):
+        fasttime.Duration.from_seconds_f64(float("nan"))
+    with pytest.raises(ValueError):
+        fasttime.Duration.from_millis_f64(float("-inf"))
+    with pytest.raises(OverflowError):
+        fasttime.Duration.from_seconds_f64(1e300)
+
+
 def test_duration_arithmetic():
     """Test duration arithmetic."""
     dur1 = fasttime.Duration.seconds(10)
@@ -193,6 +449,30 @@ def test_duration_arithmetic():
     assert neg.total_seconds() == -10
 
 
+def test_duration_to_parts():
+    """Test splitting a duration into components."""
+    dur = fasttime.Duration.seconds(90_061) + fasttime.Duration.nanoseconds(5)
+    assert dur.to_parts() == (False, 1, 1, 1, 1, 5)
+    assert (-dur).to_parts() == (True, 1, 1, 1, 1, 5)
+
+
+def test_duration_str():
+    """Test the human-readable duration string."""
+    dur = fasttime.Duration.seconds(5415) + fasttime.Duration.milliseconds(500)
+    assert str(dur) == "1h30m15.5s"
+    assert str(fasttime.Duration.milliseconds(-250)) == "-250ms"
+    assert str(fasttime.Duration.seconds(0)) == "0s"
+
+
+def test_duration_clock_string():
+    """Test the stopwatch-style HH:MM:SS form."""
+    dur = fasttime.Duration.seconds(5415) + fasttime.Duration.milliseconds(500)
+    assert dur.to_clock_string() == "01:30:15"
+    assert dur.to_clock_string(3) == "01:30:15.500"
+    assert fasttime.Duration.seconds(-300).to_clock_string() == "-00:05:00"
+    assert fasttime.Duration.seconds(360_000).to_clock_string() == "100:00:00"
+
+
 def test_duration_comparison():
     """Test duration comparisons."""
     short = fasttime.Duration.seconds(10)
@@ -224,6 +504,38 @@ def test_utc_offset_is_utc():
     assert not not_utc.is_utc()
 
 
+def test_utc_offset_whole_hours():
+    """Test the UTC constant and signed whole-hour constructor."""
+    assert fasttime.UtcOffset.UTC.is_utc()
+    assert fasttime.UtcOffset.from_whole_hours(-5).as_seconds() == -5 * 3600
+    assert str(fasttime.UtcOffset.from_whole_hours(14)) == "+14:00"
+
+    with pytest.raises(ValueError):
+        fasttime.UtcOffset.from_whole_hours(15)
+
+
+def test_utc_offset_negate_and_saturating_add():
+    """Test flipping and shifting offsets."""
+    india = fasttime.UtcOffset.from_hours_minutes(True, 5, 30)
+    assert str(india.negate()) == "-05:30"
+    assert -india == india.negate()
+    assert india.saturating_add(1800) == fasttime.UtcOffset.from_whole_hours(6)
+    assert india.saturating_add(10**6).as_seconds() == 86_400
+    assert india.saturating_add(-(10**6)).as_seconds() == -86_400
+
+
+def test_datetime_assume_offset():
+    """Test attaching an offset to a UTC DateTime."""
+    dt = fasttime.DateTime.from_unix_timestamp(0)
+    offset = fasttime.UtcOffset.from_whole_hours(2)
+
+    odt = dt.assume_offset(offset)
+    assert odt == fasttime.OffsetDateTime.from_utc(dt, offset)
+    assert str(odt) == "1970-01-01T02:00:00+02:00"
+    assert dt.assume_utc().offset.is_utc()
+    assert dt.assume_utc().utc == dt
+
+
 def test_offset_datetime_from_local():
     """Test creating offset datetimes from local time."""
     date = fasttime.Date(2024, 6, 15)
@@ -234,6 +546,24 @@ def test_offset_datetime_from_local():
     assert odt.offset == offset
 
 
+def test_offset_datetime_from_unix_timestamp():
+    """Test creating offset datetimes from an epoch timestamp."""
+    offset = fasttime.UtcOffset.from_hours_minutes(True, 5, 30)
+    odt = fasttime.OffsetDateTime.from_unix_timestamp(1_699_228_799, 500, offset)
+    assert odt.utc == fasttime.DateTime.from_unix_timestamp(1_699_228_799, 500)
+    assert odt.offset == offset
+    assert str(odt) == "2023-11-06T05:29:59.0000005+05:30"
+
+
+def test_offset_datetime_is_same_local_date():
+    """Test grouping offset datetimes by local date."""
+    late = fasttime.OffsetDateTime.parse("2024-06-16T00:30:00+02:00")
+    early = fasttime.OffsetDateTime.parse("2024-06-15T20:00:00-02:00")
+    assert late.utc.date == early.utc.date
+    assert not late.is_same_local_date(early)
+    assert early.is_same_local_date(fasttime.OffsetDateTime.parse("2024-06-15T08:00:00+09:00"))
+
+
 def test_offset_datetime_parse():
     """Test parsing offset datetimes."""
     odt = fasttime.OffsetDateTime.parse("2024-06-15T12:00:00+05:30")
@@ -250,6 +580,17 @@ def test_offset_datetime_to_local():
     assert local.date.day == 15
 
 
+def test_offset_datetime_local_components():
+    """Test local component properties on offset datetimes."""
+    odt = fasttime.OffsetDateTime.parse("2024-06-15T23:30:15.5-02:00")
+    assert (odt.year, odt.month, odt.day) == (2024, 6, 15)
+    assert (odt.hour, odt.minute, odt.second) == (23, 30, 15)
+    assert odt.nanosecond == 500_000_000
+    assert odt.local_date == fasttime.Date(2024, 6, 15)
+    assert odt.weekday().number_from_monday() == 6
+    assert odt.utc.date == fasttime.Date(2024, 6, 16)
+
+
 def test_round_trip_date():
     """Test round-trip conversion for dates."""
     original = fasttime.Date(2024, 6, 15)
@@ -276,3 +617,59 @@ def test_round_trip_offset_datetime():
     serialized = str(odt)
     
     assert original == serialized
+
+
+def test_pickle_round_trip():
+    """Test that every value type survives pickle and deepcopy."""
+    values = [
+        fasttime.Date(2024, 2, 29),
+        fasttime.Time(23, 59, 59, nanosecond=123_456_789),
+        fasttime.DateTime.parse("2024-06-15T12:30:45.123456789Z"),
+        fasttime.Duration.nanoseconds(-1_500),
+        fasttime.UtcOffset.from_hours_minutes(False, 3, 30),
+        fasttime.OffsetDateTime.parse("2024-06-15T12:00:00.5+05:30"),
+    ]
+    for value in values:
+        restored = pickle.loads(pickle.dumps(value))
+        assert type(restored) is type(value)
+        assert restored == value
+        assert str(restored) == str(value)
+        assert copy.deepcopy(value) == value
+
+
+def test_pickle_keeps_unknown_local_offset():
+    """Test that pickling keeps the -00:00 marker, which == ignores."""
+    odt = fasttime.OffsetDateTime.parse("2024-06-15T12:00:00-00:00")
+    for value in [odt, odt.offset, fasttime.UtcOffset.from_seconds(0, unknown_local=True)]:
+        assert str(pickle.loads(pickle.dumps(value))) == str(value)
+        assert str(copy.deepcopy(value)) == str(value)
+    assert str(odt.offset) == "-00:00"
+    assert odt.offset.is_unknown_local()
+    assert pickle.loads(pickle.dumps(odt)).offset.is_unknown_local()
+    assert pickle.loads(pickle.dumps(odt.offset)).is_unknown_local()
+    assert not fasttime.UtcOffset.UTC.is_unknown_local()
+    assert repr(odt.offset) == "UtcOffset.from_seconds(0, unknown_local=True)"
+    assert repr(fasttime.UtcOffset.UTC) == "UtcOffset.from_seconds(0)"
+    with pytest.raises(ValueError):
+        fasttime.UtcOffset.from_seconds(3600, unknown_local=True)
+
+
+def test_stubs_match_runtime():
+    """Test that the .pyi stubs mirror the public runtime API."""
+    stub_path = pathlib.Path(__file__).parent.parent / "fasttime" / "__init__.pyi"
+    tree = ast.parse(stub_path.read_text())
+    classes = [node for node in tree.body if isinstance(node, ast.ClassDef)]
+    assert sorted(c.name for c in classes) == sorted(fasttime.__all__)
+
+    for cls in classes:
+        runtime = getattr(fasttime, cls.name)
+        stubbed = set()
+        for node in cls.body:
+            if isinstance(node, ast.FunctionDef):
+                stubbed.add(node.name)
+            elif isinstance(node, ast.AnnAssign):
+                stubbed.add(node.target.id)
+        for name in stubbed:
+            assert hasattr(runtime, name), f"{cls.name}.{name} is stubbed but missing"
+        public = {name for name in dir(runtime) if not name.startswith("_")}
+        assert public <= stubbed, f"{cls.name} lacks stubs for {public - stubbed}"
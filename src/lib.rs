@@ -8,13 +8,23 @@
 //! - `Date` / `Time` / `DateTime` (UTC).
 //! - `Duration` with nanosecond precision.
 //! - `UtcOffset` and `OffsetDateTime` (fixed offset, RFC 3339-style).
-//! - ISO-like formatting via `Display`.
+//! - ISO-like formatting via `Display`, plus `strftime`-style
+//!   `DateTime::format_into` (and `format` with `alloc`).
 //! - Parsing of:
-//!   - `Date`: "YYYY-MM-DD"
+//!   - `Date`: "YYYY-MM-DD", or "5 Nov 2023" / "Nov 5, 2023" via
+//!     `Date::parse_named`
 //!   - `Time`: "HH:MM:SS[.fffffffff]"
 //!   - `DateTime` (UTC): "YYYY-MM-DDTHH:MM:SS[.fffffffff]Z"
 //!   - `OffsetDateTime`: "YYYY-MM-DDTHH:MM:SS[.fffffffff][Z|±HH:MM]" (RFC 3339 subset).
+//!   - ISO 8601 basic format ("20231105T235959Z") via `parse_basic` on
+//!     `Date`, `Time` and `DateTime`, with `to_basic_iso` for output.
 //! - `DateTime::now_utc()` when the `std` feature is enabled.
+//! - `String`-returning helpers (`to_iso_string`, `format`, ...) and bulk
+//!   parsing (`parse_all`, `try_parse_all`) with the `alloc` feature, which
+//!   `std` implies.
+//! - `serde` impls for `Date`/`Time`/`DateTime` (strings in text formats,
+//!   integers in binary ones) and helper modules (see
+//!   [`serde`]) with the `serde` feature.
 //!
 //! ## Python Bindings
 //!
@@ -23,11 +33,21 @@
 
 use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::str::FromStr;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
 #[cfg(feature = "python")]
 mod python;
 
+#[cfg(feature = "serde")]
+pub mod serde;
+
 /// Calendar weekday (ISO order, Monday = 1).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Weekday {
@@ -52,13 +72,139 @@ impl Weekday {
             Weekday::Sunday => 7,
         }
     }
+
+    /// English name, e.g. `"Monday"`.
+    pub fn long_name(self) -> &'static str {
+        match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+
+    /// Three-letter English abbreviation, e.g. `"Mon"`.
+    pub fn short_name(self) -> &'static str {
+        &self.long_name()[..3]
+    }
+}
+
+impl fmt::Display for Weekday {
+    /// The English name, as [`Weekday::long_name`]; honours width and
+    /// alignment (`{:<9}`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.long_name())
+    }
+}
+
+/// Calendar month (January = 1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Month {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+impl Month {
+    const ALL: [Month; 12] = [
+        Month::January,
+        Month::February,
+        Month::March,
+        Month::April,
+        Month::May,
+        Month::June,
+        Month::July,
+        Month::August,
+        Month::September,
+        Month::October,
+        Month::November,
+        Month::December,
+    ];
+
+    const NAMES: [&'static str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+
+    /// Month number, `1..=12`.
+    #[inline]
+    pub fn number(self) -> u8 {
+        self as u8 + 1
+    }
+
+    /// Inverse of [`Month::number`]; `None` outside `1..=12`.
+    #[inline]
+    pub fn from_number(number: u8) -> Option<Month> {
+        Month::ALL.get(number.checked_sub(1)? as usize).copied()
+    }
+
+    /// English name, e.g. `"January"`.
+    #[inline]
+    pub fn long_name(self) -> &'static str {
+        Month::NAMES[self as usize]
+    }
+
+    /// Three-letter English abbreviation, e.g. `"Jan"`.
+    #[inline]
+    pub fn short_name(self) -> &'static str {
+        &self.long_name()[..3]
+    }
+}
+
+impl fmt::Display for Month {
+    /// The English name, as [`Month::long_name`]; honours width and
+    /// alignment (`{:>9}`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.long_name())
+    }
+}
+
+impl FromStr for Month {
+    type Err = DateError;
+
+    /// Parse an English month name or its three-letter abbreviation,
+    /// ignoring ASCII case ("November", "nov", "NOV").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Month::NAMES
+            .iter()
+            .position(|name| s.eq_ignore_ascii_case(name) || s.eq_ignore_ascii_case(&name[..3]))
+            .map(|i| Month::ALL[i])
+            .ok_or(DateError::InvalidDate)
+    }
 }
 
 /// Errors constructing or parsing a `Date`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DateError {
-    /// Year/month/day combination is not a valid Gregorian date.
+    /// Year/month/day combination is not a valid Gregorian date
+    /// (e.g. February 30th), or the text is not a date at all.
     InvalidDate,
+    /// The month is not in `1..=12`.
+    MonthOutOfRange,
+    /// The day is not in `1..=31`.
+    DayOutOfRange,
     /// The date is outside the supported range.
     OutOfRange,
 }
@@ -74,26 +220,123 @@ pub struct Date {
 }
 
 impl Date {
+    /// Earliest representable date: `i32::MIN`-01-01.
+    pub const MIN: Date = Date {
+        year: i32::MIN,
+        month: 1,
+        day: 1,
+    };
+
+    /// Latest representable date: `i32::MAX`-12-31.
+    pub const MAX: Date = Date {
+        year: i32::MAX,
+        month: 12,
+        day: 31,
+    };
+
     /// Construct a date, validating year/month/day.
     #[inline]
     pub fn from_ymd(year: i32, month: u8, day: u8) -> Result<Self, DateError> {
         if !(1..=12).contains(&month) {
-            return Err(DateError::InvalidDate);
+            return Err(DateError::MonthOutOfRange);
+        }
+        if !(1..=31).contains(&day) {
+            return Err(DateError::DayOutOfRange);
         }
         let dim = days_in_month(year, month);
-        if day == 0 || day > dim {
+        if day > dim {
             return Err(DateError::InvalidDate);
         }
         Ok(Date { year, month, day })
     }
 
+    /// Const-evaluable [`Date::from_ymd`], returning `None` for an invalid date.
+    ///
+    /// Together with the `const` [`Date::days_since_unix_epoch`] this lets
+    /// date tables be built at compile time.
+    #[inline]
+    pub const fn from_ymd_const(year: i32, month: u8, day: u8) -> Option<Self> {
+        if month < 1 || month > 12 || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Date { year, month, day })
+    }
+
+    /// Like `FromStr`, but ignores leading and trailing whitespace (spaces,
+    /// tabs, newlines), as found in padded CSV or text columns.
+    ///
+    /// Whitespace inside the date is still rejected.
+    pub fn parse_lenient(s: &str) -> Result<Self, DateError> {
+        s.trim().parse()
+    }
+
+    /// Parse the ISO 8601 basic format "YYYYMMDD" (no separators), as used
+    /// in file names.
+    ///
+    /// The year is exactly four digits. Out-of-range fields are reported as
+    /// by `FromStr` (e.g. `MonthOutOfRange`); anything that isn't eight
+    /// digits is `InvalidDate`.
+    pub fn parse_basic(s: &str) -> Result<Self, DateError> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 8 {
+            return Err(DateError::InvalidDate);
+        }
+        let year = parse_u32_bytes(&bytes[..4], 9999).ok_or(DateError::InvalidDate)? as i32;
+        let month = parse_u32_bytes(&bytes[4..6], 99).ok_or(DateError::InvalidDate)? as u8;
+        let day = parse_u32_bytes(&bytes[6..], 99).ok_or(DateError::InvalidDate)? as u8;
+        Date::from_ymd(year, month, day)
+    }
+
+    /// Parse a date with an English month name: "5 Nov 2023",
+    /// "Nov 5, 2023" or "November 5 2023".
+    ///
+    /// The month is read by [`Month`]'s `FromStr` (full name or three-letter
+    /// abbreviation, any case). Fields are separated by whitespace, and the
+    /// day may be followed by a comma. An unknown month name is `InvalidDate`.
+    pub fn parse_named(s: &str) -> Result<Self, DateError> {
+        let mut fields = s.split_ascii_whitespace();
+        let (first, second, year) =
+            match (fields.next(), fields.next(), fields.next(), fields.next()) {
+                (Some(a), Some(b), Some(c), None) => (a, b, c),
+                _ => return Err(DateError::InvalidDate),
+            };
+        let first_is_day = first.as_bytes().first().is_some_and(u8::is_ascii_digit);
+        let (day, month) = if first_is_day {
+            (first, second)
+        } else {
+            (second.strip_suffix(',').unwrap_or(second), first)
+        };
+        let month: Month = month.parse()?;
+        let day = parse_u32_bytes(day.as_bytes(), 99).ok_or(DateError::InvalidDate)? as u8;
+        let year = parse_i32_bytes(year.as_bytes()).ok_or(DateError::InvalidDate)?;
+        Date::from_ymd(year, month.number(), day)
+    }
+
+    /// Construct the nearest valid date, clamping `month` to `1..=12` and
+    /// `day` to the length of that month (e.g. February 30th becomes the
+    /// 28th or 29th).
+    ///
+    /// The flag is `true` if any field had to be clamped.
+    pub fn from_ymd_or_clamp(year: i32, month: u8, day: u8) -> (Self, bool) {
+        let m = month.clamp(1, 12);
+        let d = day.clamp(1, days_in_month(year, m));
+        (
+            Date {
+                year,
+                month: m,
+                day: d,
+            },
+            m != month || d != day,
+        )
+    }
+
     /// Construct a date with minimal checking; debug-only asserts.
     ///
     /// Panics in debug builds if the date is invalid.
     pub const fn from_ymd_unchecked(year: i32, month: u8, day: u8) -> Self {
         // These are simple invariants, checked in debug builds only.
         debug_assert!(month >= 1 && month <= 12);
-        debug_assert!(day >= 1 && day <= 31);
+        debug_assert!(day >= 1 && day <= days_in_month(year, month));
         Date { year, month, day }
     }
 
@@ -113,6 +356,12 @@ impl Date {
         const C2: u64 = 50_504_432_782_230_121;
         const C3: u64 = 8_619_973_866_219_416;
 
+        // Reject counts outside the i32 year range up front; this also keeps
+        // `D_SHIFT - days` from overflowing.
+        if days < Date::MIN.days_since_unix_epoch() || days > Date::MAX.days_since_unix_epoch() {
+            return Err(DateError::OutOfRange);
+        }
+
         let rev: i64 = D_SHIFT - days;
 
         // 64x64 → high 64 bit multiplies via u128 with explicit u64 casts.
@@ -155,13 +404,84 @@ impl Date {
     /// This uses a modified Neri-Schneider inverse civil→days formula
     /// (as described by Ben Joffe), exact for the proleptic Gregorian calendar.
     #[inline]
-    pub fn days_since_unix_epoch(self) -> i64 {
+    pub const fn days_since_unix_epoch(self) -> i64 {
         days_from_civil(self.year, self.month, self.day)
     }
 
+    /// Days since Unix epoch as an `i32`, for compact storage.
+    ///
+    /// Returns `OutOfRange` if the day count does not fit in an `i32`
+    /// (roughly years -5_877_641..=5_881_580).
+    #[inline]
+    pub fn to_days_i32(self) -> Result<i32, DateError> {
+        i32::try_from(self.days_since_unix_epoch()).map_err(|_| DateError::OutOfRange)
+    }
+
+    /// Inverse of [`Date::to_days_i32`].
+    #[inline]
+    pub fn from_days_i32(days: i32) -> Result<Self, DateError> {
+        Date::from_days_since_unix_epoch(days as i64)
+    }
+
+    /// Same month and day in `year`; errors if that date doesn't exist
+    /// (Feb 29 in a common year).
+    #[inline]
+    pub fn with_year(self, year: i32) -> Result<Self, DateError> {
+        Date::from_ymd(year, self.month, self.day)
+    }
+
+    /// Same year and day in `month`; errors if the day doesn't exist there.
+    #[inline]
+    pub fn with_month(self, month: u8) -> Result<Self, DateError> {
+        Date::from_ymd(self.year, month, self.day)
+    }
+
+    /// Same year and month with the given `day`.
+    #[inline]
+    pub fn with_day(self, day: u8) -> Result<Self, DateError> {
+        Date::from_ymd(self.year, self.month, day)
+    }
+
+    /// Same year with the month and day of day-of-year `ordinal`; the
+    /// inverse of [`Date::ordinal`].
+    ///
+    /// Returns `InvalidDate` unless `ordinal` is in `1..=365` (`1..=366` in
+    /// leap years).
+    pub fn with_ordinal(self, ordinal: u16) -> Result<Self, DateError> {
+        if ordinal == 0 {
+            return Err(DateError::InvalidDate);
+        }
+        let mut day = ordinal;
+        for month in 1..=12 {
+            let len = days_in_month(self.year, month) as u16;
+            if day <= len {
+                return Date::from_ymd(self.year, month, day as u8);
+            }
+            day -= len;
+        }
+        Err(DateError::InvalidDate)
+    }
+
+    /// Rata Die day number: 0001-01-01 (proleptic Gregorian) is day 1.
+    #[inline]
+    pub fn rata_die(self) -> i64 {
+        self.days_since_unix_epoch() + UNIX_EPOCH_RATA_DIE
+    }
+
+    /// Inverse of [`Date::rata_die`].
+    #[inline]
+    pub fn from_rata_die(rd: i64) -> Result<Self, DateError> {
+        let days = rd
+            .checked_sub(UNIX_EPOCH_RATA_DIE)
+            .ok_or(DateError::OutOfRange)?;
+        Date::from_days_since_unix_epoch(days)
+    }
+
     /// Day of week (Monday = 1).
     ///
-    /// Unix epoch 1970-01-01 was a Thursday, so we just offset.
+    /// Unix epoch 1970-01-01 was a Thursday, so we just offset. The day count
+    /// is reduced with `rem_euclid`, so dates before 1970 (negative counts)
+    /// are handled the same way across the whole supported range.
     pub fn weekday(self) -> Weekday {
         // 1970-01-01 was Thursday (4).
         let days = self.days_since_unix_epoch();
@@ -178,6 +498,113 @@ impl Date {
         }
     }
 
+    /// Days since the preceding Monday, `0..=6` (Monday = 0), for indexing
+    /// a `[&str; 7]` of names in ISO order.
+    #[inline]
+    pub fn num_days_from_monday(self) -> u8 {
+        // 1970-01-01 (day 0) was a Thursday, three days after a Monday.
+        (self.days_since_unix_epoch() + 3).rem_euclid(7) as u8
+    }
+
+    /// Days since the preceding Sunday, `0..=6` (Sunday = 0), for indexing
+    /// a `[&str; 7]` of names in US order.
+    #[inline]
+    pub fn num_days_from_sunday(self) -> u8 {
+        (self.days_since_unix_epoch() + 4).rem_euclid(7) as u8
+    }
+
+    /// Week of the year, with weeks starting on `week_start` (US style:
+    /// [`Weekday::Sunday`]).
+    ///
+    /// The week containing January 1st is week 1, however few of its days
+    /// fall in this year, and a new week begins on each `week_start`. The
+    /// result is in `1..=54`; 54 only occurs in a leap year that begins on
+    /// the last day of a week (e.g. 2000 with Sunday-start weeks). Unlike ISO
+    /// weeks, dates are never assigned to the neighbouring year.
+    pub fn week_of_year(self, week_start: Weekday) -> u8 {
+        let jan1 = Date {
+            year: self.year,
+            month: 1,
+            day: 1,
+        };
+        // Days from the start of week 1 to January 1st.
+        let lead = (jan1.weekday().number_from_monday() as i32
+            - week_start.number_from_monday() as i32)
+            .rem_euclid(7) as u16;
+        ((self.ordinal() - 1 + lead) / 7 + 1) as u8
+    }
+
+    /// ISO 8601 week-numbering year.
+    ///
+    /// This differs from the calendar `year` for the first and last few days
+    /// of some years: 2021-01-01 is in 2020's week 53. It is an `i64` because
+    /// 2147483647-12-30 and -31 fall in week 1 of the following ISO year.
+    #[inline]
+    pub fn iso_year(self) -> i64 {
+        self.iso_week_date().0
+    }
+
+    /// ISO 8601 week number, `1..=53`. Week 1 is the week (Monday to
+    /// Sunday) containing the year's first Thursday.
+    #[inline]
+    pub fn iso_week(self) -> u8 {
+        self.iso_week_date().1
+    }
+
+    /// Date for an ISO 8601 week date, the inverse of [`Date::iso_year`],
+    /// [`Date::iso_week`] and [`Date::weekday`].
+    ///
+    /// Returns `InvalidDate` for week 0 or a week past the end of the year
+    /// (e.g. week 53 of a 52-week year), and `OutOfRange` if the date is not
+    /// representable.
+    pub fn from_iso_week(iso_year: i64, week: u8, weekday: Weekday) -> Result<Self, DateError> {
+        if week == 0 || week > 53 {
+            return Err(DateError::InvalidDate);
+        }
+        // January 4th is always in week 1.
+        let jan4 = match i32::try_from(iso_year) {
+            Ok(year) => {
+                if week > iso_weeks_in_year(year) {
+                    return Err(DateError::InvalidDate);
+                }
+                Date {
+                    year,
+                    month: 1,
+                    day: 4,
+                }
+                .days_since_unix_epoch()
+            }
+            // Week 1 of this year starts in Date::MAX's year.
+            Err(_) if iso_year == i32::MAX as i64 + 1 => Date::MAX.days_since_unix_epoch() + 4,
+            Err(_) => return Err(DateError::OutOfRange),
+        };
+        // 1970-01-01 was a Thursday: (days + 3) mod 7 counts from Monday.
+        let week1_monday = jan4 - (jan4 + 3).rem_euclid(7);
+        let days = week1_monday + (week as i64 - 1) * 7 + (weekday.number_from_monday() as i64 - 1);
+        Date::from_days_since_unix_epoch(days)
+    }
+
+    /// `(iso_year, iso_week)`.
+    fn iso_week_date(self) -> (i64, u8) {
+        let weekday = self.weekday().number_from_monday() as i32;
+        let week = (self.ordinal() as i32 - weekday + 10) / 7;
+        if week < 1 {
+            // Never reached for Date::MIN's year, whose January 1st is a Tuesday.
+            let prev = self.year - 1;
+            (prev as i64, iso_weeks_in_year(prev))
+        } else if week > iso_weeks_in_year(self.year) as i32 {
+            (self.year as i64 + 1, 1)
+        } else {
+            (self.year as i64, week as u8)
+        }
+    }
+
+    /// Number of ISO 8601 weeks in `year`; see [`iso_weeks_in_year`].
+    #[inline]
+    pub fn iso_weeks_in_year(year: i32) -> u8 {
+        iso_weeks_in_year(year)
+    }
+
     /// Day of year, 1..=365 (or 366 for leap years).
     pub fn ordinal(self) -> u16 {
         let month = self.month;
@@ -192,8 +619,285 @@ impl Date {
 
     /// Add a number of days, returning a new `Date` or `OutOfRange`.
     pub fn add_days(self, days: i64) -> Result<Date, DateError> {
-        let base = self.days_since_unix_epoch();
-        Date::from_days_since_unix_epoch(base + days)
+        self.checked_add_days(days).ok_or(DateError::OutOfRange)
+    }
+
+    /// Add a number of days, returning `None` if the result is out of range.
+    pub fn checked_add_days(self, days: i64) -> Option<Date> {
+        let target = self.days_since_unix_epoch().checked_add(days)?;
+        Date::from_days_since_unix_epoch(target).ok()
+    }
+
+    /// Subtract a number of days, returning a new `Date` or `OutOfRange`.
+    ///
+    /// Unlike `add_days(-days)`, this never negates `days`, so `i64::MIN`
+    /// is handled without overflow.
+    pub fn sub_days(self, days: i64) -> Result<Date, DateError> {
+        self.checked_sub_days(days).ok_or(DateError::OutOfRange)
+    }
+
+    /// Subtract a number of days, returning `None` if the result is out of
+    /// range.
+    pub fn checked_sub_days(self, days: i64) -> Option<Date> {
+        let target = self.days_since_unix_epoch().checked_sub(days)?;
+        Date::from_days_since_unix_epoch(target).ok()
+    }
+
+    /// Add a number of days, clamping to [`Date::MIN`]/[`Date::MAX`].
+    pub fn saturating_add_days(self, days: i64) -> Date {
+        match self.checked_add_days(days) {
+            Some(date) => date,
+            None if days < 0 => Date::MIN,
+            None => Date::MAX,
+        }
+    }
+
+    /// Apply the whole days of `dur` to this date and return the sub-day
+    /// remainder.
+    ///
+    /// Days are floored, so the remainder is always in `[0, 24h)`: applying
+    /// `-1h` yields the previous day and a `23h` remainder.
+    pub fn apply_duration(self, dur: Duration) -> Result<(Date, Duration), DateError> {
+        const NANOS_PER_DAY: i128 = 86_400 * 1_000_000_000;
+        let nanos = dur.total_nanos();
+        let days =
+            i64::try_from(nanos.div_euclid(NANOS_PER_DAY)).map_err(|_| DateError::OutOfRange)?;
+        let date = self.add_days(days)?;
+        Ok((date, Duration::nanoseconds(nanos.rem_euclid(NANOS_PER_DAY))))
+    }
+
+    /// Add a number of calendar months, clamping the day to the length of
+    /// the target month (Jan 31 + 1 month = Feb 28/29).
+    pub fn add_months(self, months: i64) -> Result<Date, DateError> {
+        let index = (self.year as i64 * 12 + (self.month as i64 - 1))
+            .checked_add(months)
+            .ok_or(DateError::OutOfRange)?;
+        date_from_month_index(index, self.day).ok_or(DateError::OutOfRange)
+    }
+
+    /// Completed years from `self` to `later`, as for an age or anniversary.
+    ///
+    /// A year completes on the same month and day, so someone born on
+    /// 2000-03-01 is 22 on 2023-02-28 and 23 on 2023-03-01. A February 29th
+    /// start has its anniversary on February 28th in common years. If
+    /// `later` precedes `self` the count is negative (`-later.years_between(self)`);
+    /// it saturates at the `i32` bounds.
+    pub fn years_between(self, later: Date) -> i32 {
+        if later < self {
+            return later.years_between(self).saturating_neg();
+        }
+        let mut years = later.year as i64 - self.year as i64;
+        let day = self.day.min(days_in_month(later.year, self.month));
+        if (later.month, later.day) < (self.month, day) {
+            years -= 1;
+        }
+        years.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+
+    /// Age in completed years on `reference` for someone born on `self`;
+    /// see [`Date::years_between`] for the February 29th rule.
+    ///
+    /// Returns `None` if `reference` is before `self`.
+    pub fn age_on(self, reference: Date) -> Option<u32> {
+        if reference < self {
+            return None;
+        }
+        Some(self.years_between(reference) as u32)
+    }
+
+    /// Restricts `self` to the inclusive range `min..=max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, like [`Ord::clamp`].
+    pub fn clamp(self, min: Date, max: Date) -> Date {
+        assert!(min <= max, "clamp: min is after max");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Recurring dates on `day_of_month`, one per month, starting with the
+    /// first occurrence on or after `start`.
+    ///
+    /// Months shorter than `day_of_month` yield their last day instead (day
+    /// 31 gives Feb 28 or 29, Apr 30, ...); the nominal day is kept for the
+    /// following months. The iterator is infinite up to [`Date::MAX`]; pair
+    /// it with `.take(n)`. A `day_of_month` outside `1..=31` yields nothing.
+    pub fn monthly(start: Date, day_of_month: u8) -> impl Iterator<Item = Date> {
+        let mut index = start.year as i64 * 12 + (start.month as i64 - 1);
+        if date_from_month_index(index, day_of_month).is_some_and(|d| d < start) {
+            index += 1;
+        }
+        let valid = (1..=31).contains(&day_of_month);
+        core::iter::from_fn(move || {
+            if !valid {
+                return None;
+            }
+            let date = date_from_month_index(index, day_of_month)?;
+            index += 1;
+            Some(date)
+        })
+    }
+
+    /// The next calendar day, or `OutOfRange` past [`Date::MAX`].
+    pub fn succ(self) -> Result<Date, DateError> {
+        self.succ_opt().ok_or(DateError::OutOfRange)
+    }
+
+    /// The previous calendar day, or `OutOfRange` before [`Date::MIN`].
+    pub fn pred(self) -> Result<Date, DateError> {
+        self.pred_opt().ok_or(DateError::OutOfRange)
+    }
+
+    /// Like [`Date::succ`], returning `None` on overflow.
+    pub fn succ_opt(self) -> Option<Date> {
+        if self.day < days_in_month(self.year, self.month) {
+            Some(Date::from_ymd_unchecked(
+                self.year,
+                self.month,
+                self.day + 1,
+            ))
+        } else {
+            first_of_next_month(self)
+        }
+    }
+
+    /// Like [`Date::pred`], returning `None` on overflow.
+    pub fn pred_opt(self) -> Option<Date> {
+        if self.day > 1 {
+            Some(Date::from_ymd_unchecked(
+                self.year,
+                self.month,
+                self.day - 1,
+            ))
+        } else if self.month > 1 {
+            let month = self.month - 1;
+            Some(Date::from_ymd_unchecked(
+                self.year,
+                month,
+                days_in_month(self.year, month),
+            ))
+        } else {
+            let year = self.year.checked_sub(1)?;
+            Some(Date::from_ymd_unchecked(year, 12, 31))
+        }
+    }
+
+    /// Iterate backward one day at a time: `start`, the day before, and so
+    /// on, ending after [`Date::MIN`]. Lazy; pair with `.take(n)`.
+    pub fn iter_days_back(start: Date) -> impl Iterator<Item = Date> {
+        core::iter::successors(Some(start), |d| d.pred_opt())
+    }
+
+    /// Split the half-open range `[start, end)` into calendar-month chunks.
+    ///
+    /// Each item is `(chunk_start, chunk_end_exclusive)`; the first and last
+    /// chunks are clipped to `start` and `end`. Yields nothing if
+    /// `start >= end`.
+    pub fn chunk_by_month(start: Date, end: Date) -> impl Iterator<Item = (Date, Date)> {
+        let mut cursor = start;
+        core::iter::from_fn(move || {
+            if cursor >= end {
+                return None;
+            }
+            let chunk_end = match first_of_next_month(cursor) {
+                Some(next) if next < end => next,
+                _ => end,
+            };
+            let chunk = (cursor, chunk_end);
+            cursor = chunk_end;
+            Some(chunk)
+        })
+    }
+
+    /// Format with the date specifiers of [`DateTime::format_into`] (`%Y`,
+    /// `%y`, `%m`, `%d`, `%j`, `%u`, `%F` and `%%`), writing into `out`.
+    ///
+    /// Time specifiers such as `%H` are reported as `UnknownSpecifier`.
+    pub fn format_into<W: fmt::Write>(
+        &self,
+        pattern: &str,
+        out: &mut W,
+    ) -> Result<(), FormatError> {
+        format_pattern(out, pattern, Some(*self), None)
+    }
+
+    /// Like [`Date::format_into`], returning a `String`.
+    #[cfg(feature = "alloc")]
+    pub fn format(&self, pattern: &str) -> Result<String, FormatError> {
+        let mut out = String::with_capacity(pattern.len() + 8);
+        self.format_into(pattern, &mut out)?;
+        Ok(out)
+    }
+
+    /// The `Display` form ("YYYY-MM-DD") as a `String`, without needing
+    /// `std`'s `ToString`.
+    #[cfg(feature = "alloc")]
+    pub fn to_iso_string(&self) -> String {
+        display_to_string(self, 10)
+    }
+
+    /// The ISO 8601 basic form, "YYYYMMDD"; see [`Date::parse_basic`].
+    #[cfg(feature = "alloc")]
+    pub fn to_basic_iso(&self) -> String {
+        display_to_string(
+            &format_args!("{:04}{:02}{:02}", self.year, self.month, self.day),
+            8,
+        )
+    }
+
+    /// Parse `s` laid out as `pattern`, e.g. `"%d/%m/%Y"`.
+    ///
+    /// Supported specifiers: `%Y` (optional sign and one or more digits, or
+    /// at most four when another numeric specifier follows directly, as in
+    /// `"%Y%m%d"`), `%y` (two digits), `%m` and `%d` (two digits each) and
+    /// `%%`. Two-digit
+    /// years use the POSIX window: `69..=99` are 1969-1999 and `00..=68` are
+    /// 2000-2068. Any other pattern text must match literally. Year, month
+    /// and day must all be present.
+    ///
+    /// Returns `InvalidFormat` if the text doesn't follow the pattern and
+    /// `InvalidDate` if the fields don't form a valid date.
+    pub fn parse_from_format(s: &str, pattern: &str) -> Result<Date, ParseError> {
+        parse_date_pattern(s, pattern)
+    }
+
+    /// Pack into an `i64` as `year << 9 | month << 5 | day`.
+    ///
+    /// Bits 0..5 hold the day, bits 5..9 the month and the remaining
+    /// high bits the (signed) year. Packed values sort like the dates.
+    #[inline]
+    pub const fn to_packed(self) -> i64 {
+        ((self.year as i64) << 9) | ((self.month as i64) << 5) | self.day as i64
+    }
+
+    /// Inverse of [`Date::to_packed`], validating the unpacked fields.
+    #[inline]
+    pub fn from_packed(packed: i64) -> Result<Self, DateError> {
+        let year = i32::try_from(packed >> 9).map_err(|_| DateError::OutOfRange)?;
+        let month = ((packed >> 5) & 0xF) as u8;
+        let day = (packed & 0x1F) as u8;
+        Date::from_ymd(year, month, day)
+    }
+}
+
+/// Number of ISO 8601 weeks in `year`: 53 if January 1st is a Thursday, or
+/// a Wednesday in a leap year; otherwise 52.
+pub fn iso_weeks_in_year(year: i32) -> u8 {
+    let jan1 = Date {
+        year,
+        month: 1,
+        day: 1,
+    };
+    match jan1.weekday() {
+        Weekday::Thursday => 53,
+        Weekday::Wednesday if is_leap_year(year) => 53,
+        _ => 52,
     }
 }
 
@@ -221,7 +925,7 @@ impl fmt::Display for Date {
 impl FromStr for Date {
     type Err = DateError;
 
-    /// Parse "YYYY-MM-DD" (no timezone).
+    /// Parse "YYYY-MM-DD" or the ISO week date "YYYY-Www-D" (no timezone).
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let bytes = s.as_bytes();
         if bytes.is_empty() {
@@ -256,8 +960,12 @@ impl FromStr for Date {
         };
 
         let y = parse_i32_bytes(&bytes[..first]).ok_or(DateError::InvalidDate)?;
-        let m = parse_u32_bytes(&bytes[first + 1..second], 12).ok_or(DateError::InvalidDate)? as u8;
-        let d = parse_u32_bytes(&bytes[second + 1..], 31).ok_or(DateError::InvalidDate)? as u8;
+        if bytes[first + 1] == b'W' {
+            return parse_iso_week_date(y, &bytes[first + 2..second], &bytes[second + 1..]);
+        }
+        // Let `from_ymd` report which field is out of range.
+        let m = parse_u32_bytes(&bytes[first + 1..second], 99).ok_or(DateError::InvalidDate)? as u8;
+        let d = parse_u32_bytes(&bytes[second + 1..], 99).ok_or(DateError::InvalidDate)? as u8;
         Date::from_ymd(y, m, d)
     }
 }
@@ -296,6 +1004,101 @@ impl Time {
         })
     }
 
+    /// Replace the hour, keeping the other fields.
+    #[inline]
+    pub fn with_hour(self, hour: u8) -> Result<Self, TimeError> {
+        Time::from_hms_nano(hour, self.minute, self.second, self.nanosecond)
+    }
+
+    /// Replace the minute, keeping the other fields.
+    #[inline]
+    pub fn with_minute(self, minute: u8) -> Result<Self, TimeError> {
+        Time::from_hms_nano(self.hour, minute, self.second, self.nanosecond)
+    }
+
+    /// Replace the second, keeping the other fields.
+    #[inline]
+    pub fn with_second(self, second: u8) -> Result<Self, TimeError> {
+        Time::from_hms_nano(self.hour, self.minute, second, self.nanosecond)
+    }
+
+    /// Replace the nanosecond, keeping the other fields.
+    #[inline]
+    pub fn with_nanosecond(self, nanosecond: u32) -> Result<Self, TimeError> {
+        Time::from_hms_nano(self.hour, self.minute, self.second, nanosecond)
+    }
+
+    /// Write "HH:MM:SS" followed by exactly `digits` fractional digits,
+    /// without allocating.
+    ///
+    /// The nanosecond field is truncated (never rounded, so the output never
+    /// rolls over into the next second). `digits == 0` omits the decimal
+    /// point; values above 9 are treated as 9.
+    pub fn format_with_precision_into<W: fmt::Write>(
+        &self,
+        out: &mut W,
+        digits: u8,
+    ) -> fmt::Result {
+        write!(
+            out,
+            "{:02}:{:02}:{:02}",
+            self.hour, self.minute, self.second
+        )?;
+        let digits = digits.min(9) as u32;
+        if digits == 0 {
+            return Ok(());
+        }
+        let frac = self.nanosecond / 10u32.pow(9 - digits);
+        write!(out, ".{:0width$}", frac, width = digits as usize)
+    }
+
+    /// Like [`Time::format_with_precision_into`], returning a `String`.
+    #[cfg(feature = "alloc")]
+    pub fn format_with_precision(&self, digits: u8) -> String {
+        let mut out = String::with_capacity(18);
+        let _ = self.format_with_precision_into(&mut out, digits);
+        out
+    }
+
+    /// Formats using the time specifiers of [`DateTime::format_into`] (`%H`,
+    /// `%M`, `%S`, `%f`, `%T` and `%%`), writing into `out`.
+    ///
+    /// Date specifiers such as `%Y` are reported as `UnknownSpecifier`.
+    pub fn format_into<W: fmt::Write>(
+        &self,
+        pattern: &str,
+        out: &mut W,
+    ) -> Result<(), FormatError> {
+        format_pattern(out, pattern, None, Some(*self))
+    }
+
+    /// Like [`Time::format_into`], returning a `String`.
+    #[cfg(feature = "alloc")]
+    pub fn format(&self, pattern: &str) -> Result<String, FormatError> {
+        let mut out = String::with_capacity(pattern.len() + 8);
+        self.format_into(pattern, &mut out)?;
+        Ok(out)
+    }
+
+    /// The `Display` form ("HH:MM:SS[.fffffffff]") as a `String`, without
+    /// needing `std`'s `ToString`.
+    #[cfg(feature = "alloc")]
+    pub fn to_iso_string(&self) -> String {
+        display_to_string(self, 18)
+    }
+
+    /// The ISO 8601 basic form, "HHMMSS[.fffffffff]", with the fraction
+    /// trimmed as in `Display`; see [`Time::parse_basic`].
+    #[cfg(feature = "alloc")]
+    pub fn to_basic_iso(&self) -> String {
+        let hms = format_args!("{:02}{:02}{:02}", self.hour, self.minute, self.second);
+        if self.nanosecond == 0 {
+            return display_to_string(&hms, 6);
+        }
+        let (frac, width) = trim_fraction_nanos(self.nanosecond);
+        display_to_string(&format_args!("{}.{:0width$}", hms, frac, width = width), 16)
+    }
+
     /// Total seconds since midnight (ignores nanoseconds).
     #[inline]
     pub fn seconds_since_midnight(self) -> u32 {
@@ -308,6 +1111,69 @@ impl Time {
         self.seconds_since_midnight() as u64 * 1_000_000_000 + self.nanosecond as u64
     }
 
+    /// `self - other` on the same day, in `-24h < d < 24h`; negative if
+    /// `other` is later. Never wraps across midnight.
+    #[inline]
+    pub fn difference(self, other: Time) -> Duration {
+        Duration::nanoseconds(
+            self.nanos_since_midnight() as i128 - other.nanos_since_midnight() as i128,
+        )
+    }
+
+    /// Distance between two clock times going whichever way round the clock
+    /// is shorter, so 23:00 and 01:00 are two hours apart. At most 12 hours.
+    #[inline]
+    pub fn abs_difference(self, other: Time) -> Duration {
+        const NANOS_PER_DAY: u64 = 86_400 * 1_000_000_000;
+        let direct = self
+            .nanos_since_midnight()
+            .abs_diff(other.nanos_since_midnight());
+        Duration::nanoseconds(direct.min(NANOS_PER_DAY - direct) as i128)
+    }
+
+    /// Inverse of [`Time::nanos_since_midnight`]; `InvalidTime` for values
+    /// of 24 hours (86 400 000 000 000 ns) or more.
+    #[inline]
+    pub fn from_nanos_since_midnight(nanos: u64) -> Result<Self, TimeError> {
+        Time::from_packed_nanos(nanos)
+    }
+
+    /// Round to the nearest whole second, half up (500 ms rounds up).
+    ///
+    /// The flag is `true` if rounding rolled over into the next day, in
+    /// which case the time is `00:00:00`; unlike truncation, 23:59:59.6
+    /// rounds to midnight of the following day.
+    #[inline]
+    pub fn round_to_second(self) -> (Time, bool) {
+        self.round_to_nanos(1_000_000_000)
+    }
+
+    /// Like [`Time::round_to_second`], to the nearest millisecond.
+    #[inline]
+    pub fn round_to_millis(self) -> (Time, bool) {
+        self.round_to_nanos(1_000_000)
+    }
+
+    /// Like [`Time::round_to_second`], to the nearest microsecond.
+    #[inline]
+    pub fn round_to_micros(self) -> (Time, bool) {
+        self.round_to_nanos(1_000)
+    }
+
+    fn round_to_nanos(self, unit: u64) -> (Time, bool) {
+        const NANOS_PER_DAY: u64 = 86_400 * 1_000_000_000;
+        let rounded = (self.nanos_since_midnight() + unit / 2) / unit * unit;
+        let wrapped = rounded >= NANOS_PER_DAY;
+        let nanos = if wrapped {
+            rounded - NANOS_PER_DAY
+        } else {
+            rounded
+        };
+        // `nanos` is below NANOS_PER_DAY, so this cannot fail.
+        let time = Time::from_packed_nanos(nanos).unwrap_or(self);
+        (time, wrapped)
+    }
+
     /// Build from seconds and nanoseconds since midnight.
     #[inline]
     pub fn from_seconds_nanos(secs: u32, nanos: u32) -> Result<Self, TimeError> {
@@ -320,7 +1186,70 @@ impl Time {
         let second = (rem % 60) as u8;
         Time::from_hms_nano(hour, minute, second, nanos)
     }
-}
+
+    /// Pack into a `u64` holding nanoseconds since midnight.
+    #[inline]
+    pub fn to_packed_nanos(self) -> u64 {
+        self.nanos_since_midnight()
+    }
+
+    /// Inverse of [`Time::to_packed_nanos`].
+    #[inline]
+    pub fn from_packed_nanos(packed: u64) -> Result<Self, TimeError> {
+        if packed >= 86_400 * 1_000_000_000 {
+            return Err(TimeError::InvalidTime);
+        }
+        let secs = (packed / 1_000_000_000) as u32;
+        let nanos = (packed % 1_000_000_000) as u32;
+        Time::from_seconds_nanos(secs, nanos)
+    }
+
+    /// Like `FromStr`, but ignores leading and trailing whitespace; see
+    /// [`Date::parse_lenient`].
+    pub fn parse_lenient(s: &str) -> Result<Self, TimeError> {
+        s.trim().parse()
+    }
+
+    /// Parse the ISO 8601 basic format "HHMMSS[.fffffffff]" (no
+    /// separators). Fractions are handled as by `FromStr`.
+    pub fn parse_basic(s: &str) -> Result<Self, TimeError> {
+        let bytes = s.as_bytes();
+        let (hms, frac) = match bytes.iter().position(|&b| b == b'.') {
+            Some(idx) => (&bytes[..idx], Some(&bytes[idx + 1..])),
+            None => (bytes, None),
+        };
+        if hms.len() != 6 {
+            return Err(TimeError::InvalidTime);
+        }
+        let h = parse_u32_bytes(&hms[..2], 23).ok_or(TimeError::InvalidTime)? as u8;
+        let m = parse_u32_bytes(&hms[2..4], 59).ok_or(TimeError::InvalidTime)? as u8;
+        let sec = parse_u32_bytes(&hms[4..], 59).ok_or(TimeError::InvalidTime)? as u8;
+        let nanos = match frac {
+            Some(fr) => parse_fraction_nanos(fr).ok_or(TimeError::InvalidTime)?,
+            None => 0,
+        };
+        Time::from_hms_nano(h, m, sec, nanos)
+    }
+
+    /// Parse "HH:MM:SS[.fffffffff]" followed by an RFC 3339 offset
+    /// (`Z` or `±HH:MM`), returning the time and offset separately.
+    ///
+    /// The offset is required: input without one is `InvalidTime` rather
+    /// than silently defaulting to UTC.
+    pub fn parse_with_offset(s: &str) -> Result<(Time, UtcOffset), TimeError> {
+        let bytes = s.as_bytes();
+        let split = match bytes.last() {
+            Some(b'Z' | b'z') => bytes.len() - 1,
+            _ => bytes
+                .iter()
+                .rposition(|&b| b == b'+' || b == b'-')
+                .ok_or(TimeError::InvalidTime)?,
+        };
+        let time = s[..split].parse()?;
+        let offset = parse_rfc3339_offset(&s[split..]).map_err(|_| TimeError::InvalidTime)?;
+        Ok((time, offset))
+    }
+}
 
 impl PartialOrd for Time {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -367,6 +1296,8 @@ impl FromStr for Time {
     type Err = TimeError;
 
     /// Parse "HH:MM:SS[.fffffffff]".
+    ///
+    /// Fractions longer than nine digits are truncated to nanoseconds.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let bytes = s.as_bytes();
         let (hms_bytes, frac_bytes) = match bytes.iter().position(|&b| b == b'.') {
@@ -410,13 +1341,17 @@ impl FromStr for Time {
 }
 
 /// Signed duration with nanosecond precision.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Duration {
     nanos: i128,
 }
 
 impl Duration {
     pub const ZERO: Duration = Duration { nanos: 0 };
+    /// Largest representable duration (`i128::MAX` nanoseconds).
+    pub const MAX: Duration = Duration { nanos: i128::MAX };
+    /// Smallest (most negative) representable duration (`i128::MIN` nanoseconds).
+    pub const MIN: Duration = Duration { nanos: i128::MIN };
 
     #[inline(always)]
     pub fn seconds(secs: i64) -> Duration {
@@ -425,6 +1360,42 @@ impl Duration {
         }
     }
 
+    /// `days` periods of 24 hours.
+    #[inline]
+    pub fn days(days: i64) -> Duration {
+        Duration {
+            nanos: (days as i128) * 86_400 * 1_000_000_000,
+        }
+    }
+
+    /// `weeks` periods of 7 days.
+    #[inline]
+    pub fn weeks(weeks: i64) -> Duration {
+        Duration {
+            nanos: (weeks as i128) * 7 * 86_400 * 1_000_000_000,
+        }
+    }
+
+    /// [`Duration::days`] for a wide `i128` day count, returning `None`
+    /// if the result doesn't fit in [`Duration::MAX`]/[`Duration::MIN`].
+    ///
+    /// Every `i64` count fits, which is why [`Duration::days`] itself is
+    /// infallible.
+    #[inline]
+    pub fn checked_days(days: i128) -> Option<Duration> {
+        days.checked_mul(86_400 * 1_000_000_000)
+            .map(Duration::nanoseconds)
+    }
+
+    /// [`Duration::weeks`] for a wide `i128` week count, returning `None` on
+    /// overflow; see [`Duration::checked_days`].
+    #[inline]
+    pub fn checked_weeks(weeks: i128) -> Option<Duration> {
+        weeks
+            .checked_mul(7 * 86_400 * 1_000_000_000)
+            .map(Duration::nanoseconds)
+    }
+
     pub fn milliseconds(ms: i64) -> Duration {
         Duration {
             nanos: (ms as i128) * 1_000_000,
@@ -441,14 +1412,177 @@ impl Duration {
         Duration { nanos: ns }
     }
 
+    /// Duration from fractional seconds, e.g. `1.5` or `-0.25`.
+    ///
+    /// The value is rounded to the nearest nanosecond, ties away from zero.
+    /// `NaN` and infinities are `NotFinite`; values outside the `i128`
+    /// nanosecond range are `Overflow`.
+    pub fn from_seconds_f64(secs: f64) -> Result<Duration, DurationError> {
+        duration_from_f64(secs, 1_000_000_000.0)
+    }
+
+    /// Duration from fractional milliseconds; see
+    /// [`Duration::from_seconds_f64`] for rounding and errors.
+    pub fn from_millis_f64(ms: f64) -> Result<Duration, DurationError> {
+        duration_from_f64(ms, 1_000_000.0)
+    }
+
+    /// Parse an ISO 8601 duration such as `PT1H30M`, `-PT0.25S` or `P2DT3H`,
+    /// the form written by `{:#}`.
+    ///
+    /// Supported designators are `W` and `D` before `T`, and `H`, `M`, `S`
+    /// after it. Years and months are rejected (`InvalidUnit`) since their
+    /// length depends on the calendar. Only the last component may have a
+    /// fraction; digits finer than a nanosecond are truncated.
+    pub fn parse_iso8601(s: &str) -> Result<Duration, DurationParseError> {
+        parse_iso8601_duration(s)
+    }
+
+    /// Parse a Go-style duration as used in config files and CLI flags,
+    /// e.g. `1h30m`, `250ms`, `-2.5s`, `1.5h` or `100µs`.
+    ///
+    /// Accepts everything `FromStr` does, plus the units `µs`/`μs` and `d`
+    /// (24 hours). Any component may have a fraction; digits finer than a
+    /// nanosecond are truncated. Values that don't fit are reported as
+    /// `Overflow`.
+    pub fn parse_humantime(s: &str) -> Result<Duration, DurationParseError> {
+        parse_unit_duration(s, humantime_unit_nanos)
+    }
+
+    /// Arithmetic mean, truncated toward zero to whole nanoseconds.
+    ///
+    /// Returns `None` for an empty slice. The sum is accumulated in `i128`
+    /// nanoseconds; if it overflows (only possible with durations of about
+    /// 1e21 years) the result is also `None` rather than a wrapped value.
+    pub fn mean(durations: &[Duration]) -> Option<Duration> {
+        let count = i128::try_from(durations.len()).ok()?;
+        if count == 0 {
+            return None;
+        }
+        let mut sum: i128 = 0;
+        for d in durations {
+            sum = sum.checked_add(d.nanos)?;
+        }
+        Some(Duration { nanos: sum / count })
+    }
+
+    /// Median, sorting `durations` in place.
+    ///
+    /// Returns `None` for an empty slice. For an even count this is the mean
+    /// of the two middle values, truncated toward zero; it cannot overflow.
+    pub fn median(durations: &mut [Duration]) -> Option<Duration> {
+        if durations.is_empty() {
+            return None;
+        }
+        durations.sort_unstable();
+        let mid = durations.len() / 2;
+        if durations.len() % 2 == 1 {
+            return Some(durations[mid]);
+        }
+        let (a, b) = (durations[mid - 1].nanos, durations[mid].nanos);
+        // a <= b, so the floor of the midpoint is a + diff / 2; a negative
+        // half-way value is then rounded up toward zero.
+        let diff = b.abs_diff(a);
+        let floor = a + (diff / 2) as i128;
+        let nanos = if diff % 2 == 1 && floor < 0 {
+            floor + 1
+        } else {
+            floor
+        };
+        Some(Duration { nanos })
+    }
+
+    /// `-self`, or `None` for [`Duration::MIN`], whose negation does not fit.
+    #[inline]
+    pub fn checked_neg(self) -> Option<Duration> {
+        self.nanos.checked_neg().map(|nanos| Duration { nanos })
+    }
+
     pub fn total_seconds(self) -> f64 {
         self.nanos as f64 / 1_000_000_000.0
     }
 
+    /// Total seconds rounded to `decimals` places (half away from zero).
+    ///
+    /// Rounding is done on the integer nanosecond count, so the result
+    /// doesn't depend on floating-point rounding of intermediate values.
+    /// `decimals` above 9 behave like 9.
+    pub fn to_seconds_rounded(self, decimals: u8) -> f64 {
+        let decimals = decimals.min(9) as u32;
+        let unit = 10i128.pow(9 - decimals);
+        let mut units = self.nanos / unit;
+        if (self.nanos % unit).abs() * 2 >= unit {
+            units += self.nanos.signum();
+        }
+        units as f64 / 10i128.pow(decimals) as f64
+    }
+
     #[inline(always)]
     pub fn total_nanos(self) -> i128 {
         self.nanos
     }
+
+    /// Split into days (of exactly 24 hours), hours, minutes, seconds and
+    /// nanoseconds, with the sign in `negative`.
+    pub fn to_parts(self) -> DurationParts {
+        let abs = self.nanos.unsigned_abs();
+        let nanoseconds = (abs % 1_000_000_000) as u32;
+        let secs = abs / 1_000_000_000;
+        DurationParts {
+            negative: self.nanos < 0,
+            days: secs / 86_400,
+            hours: (secs % 86_400 / 3_600) as u8,
+            minutes: (secs % 3_600 / 60) as u8,
+            seconds: (secs % 60) as u8,
+            nanoseconds,
+        }
+    }
+
+    /// Write a stopwatch-style "HH:MM:SS" (e.g. `01:30:15`, `-00:05:00`)
+    /// followed by exactly `digits` fractional digits, without allocating.
+    ///
+    /// Hours are at least two digits and are not wrapped into days
+    /// (`36:00:00`). Sub-second parts are truncated toward zero, like
+    /// [`Time::format_with_precision_into`]; `digits == 0` omits the decimal
+    /// point and values above 9 are treated as 9. A value that truncates to
+    /// zero is written without a sign.
+    pub fn format_clock_into<W: fmt::Write>(&self, out: &mut W, digits: u8) -> fmt::Result {
+        let digits = digits.min(9) as u32;
+        let abs = self.nanos.unsigned_abs();
+        let frac = (abs % 1_000_000_000) as u32 / 10u32.pow(9 - digits);
+        let secs = abs / 1_000_000_000;
+        if self.nanos < 0 && (secs > 0 || frac > 0) {
+            out.write_char('-')?;
+        }
+        write!(
+            out,
+            "{:02}:{:02}:{:02}",
+            secs / 3_600,
+            secs % 3_600 / 60,
+            secs % 60
+        )?;
+        if digits == 0 {
+            return Ok(());
+        }
+        write!(out, ".{:0width$}", frac, width = digits as usize)
+    }
+
+    /// [`Duration::format_clock_into`] with whole seconds, as a `String`.
+    #[cfg(feature = "alloc")]
+    pub fn to_clock_string(&self) -> String {
+        let mut out = String::with_capacity(9);
+        let _ = self.format_clock_into(&mut out, 0);
+        out
+    }
+
+    /// Order against a (non-negative) `core::time::Duration`.
+    fn cmp_core(&self, other: &core::time::Duration) -> Ordering {
+        if self.nanos < 0 {
+            Ordering::Less
+        } else {
+            (self.nanos as u128).cmp(&other.as_nanos())
+        }
+    }
 }
 
 impl core::ops::Add for Duration {
@@ -488,19 +1622,311 @@ impl Ord for Duration {
     }
 }
 
-/// Combined UTC date and time (no time zone).
+// Comparisons with `core::time::Duration`, which is always non-negative.
+
+impl PartialEq<core::time::Duration> for Duration {
+    fn eq(&self, other: &core::time::Duration) -> bool {
+        self.cmp_core(other) == Ordering::Equal
+    }
+}
+
+impl PartialEq<Duration> for core::time::Duration {
+    fn eq(&self, other: &Duration) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<core::time::Duration> for Duration {
+    fn partial_cmp(&self, other: &core::time::Duration) -> Option<Ordering> {
+        Some(self.cmp_core(other))
+    }
+}
+
+impl PartialOrd<Duration> for core::time::Duration {
+    fn partial_cmp(&self, other: &Duration) -> Option<Ordering> {
+        Some(other.cmp_core(self).reverse())
+    }
+}
+
+impl fmt::Debug for Duration {
+    /// `Duration(1h30m)`, using the parseable `Display` form.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Duration({})", self)
+    }
+}
+
+impl fmt::Display for Duration {
+    /// Human-compact form by default (`1h30m15.5s`, `-250ms`, `0s`),
+    /// ISO 8601 with the alternate flag (`{:#}` → `PT1H30M15.5S`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let abs = self.nanos.unsigned_abs();
+        if self.nanos < 0 {
+            f.write_str("-")?;
+        }
+        let secs = abs / 1_000_000_000;
+        let sub = (abs % 1_000_000_000) as u32;
+        let hours = secs / 3600;
+        let minutes = (secs % 3600) / 60;
+        let seconds = secs % 60;
+
+        if f.alternate() {
+            f.write_str("PT")?;
+            if hours > 0 {
+                write!(f, "{}H", hours)?;
+            }
+            if minutes > 0 {
+                write!(f, "{}M", minutes)?;
+            }
+            if seconds > 0 || sub > 0 || abs == 0 {
+                write_decimal(f, seconds, sub, 9)?;
+                f.write_str("S")?;
+            }
+            return Ok(());
+        }
+
+        if abs == 0 {
+            return f.write_str("0s");
+        }
+        if secs == 0 {
+            // Sub-second: pick the largest unit that keeps an integer part.
+            return if sub >= 1_000_000 {
+                write_decimal(f, (sub / 1_000_000) as u128, sub % 1_000_000, 6)?;
+                f.write_str("ms")
+            } else if sub >= 1_000 {
+                write_decimal(f, (sub / 1_000) as u128, sub % 1_000, 3)?;
+                f.write_str("us")
+            } else {
+                write!(f, "{}ns", sub)
+            };
+        }
+        if hours > 0 {
+            write!(f, "{}h", hours)?;
+        }
+        if minutes > 0 {
+            write!(f, "{}m", minutes)?;
+        }
+        if seconds > 0 || sub > 0 {
+            write_decimal(f, seconds, sub, 9)?;
+            f.write_str("s")?;
+        }
+        Ok(())
+    }
+}
+
+/// A [`Duration`] split into calendar-free components; see [`Duration::to_parts`].
+///
+/// The sign is factored out, so every component is non-negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DurationParts {
+    pub negative: bool,
+    pub days: u128,
+    pub hours: u8,        // 0..=23
+    pub minutes: u8,      // 0..=59
+    pub seconds: u8,      // 0..=59
+    pub nanoseconds: u32, // 0..=999_999_999
+}
+
+/// A calendar quantity for [`DateTime::checked_add_unit`].
+///
+/// Unlike a [`Duration`], months and years have no fixed length; adding
+/// them clamps the day to the end of the target month.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalendarUnit {
+    Days(i64),
+    Weeks(i64),
+    Months(i32),
+    Years(i32),
+}
+
+/// Errors parsing a `Duration` from text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationParseError {
+    /// The input is empty or contains only a sign.
+    Empty,
+    /// A component is missing its number or the number is malformed.
+    InvalidNumber,
+    /// A component has a missing or unrecognized unit.
+    InvalidUnit,
+    /// The value does not fit in a `Duration`.
+    Overflow,
+}
+
+/// Errors constructing a `Duration` from a float.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationError {
+    /// The input is `NaN` or infinite.
+    NotFinite,
+    /// The value does not fit in a `Duration`.
+    Overflow,
+}
+
+impl FromStr for Duration {
+    type Err = DurationParseError;
+
+    /// Parse the human-compact form produced by `Display`, e.g. `1h30m15.5s`,
+    /// `-250ms` or `0s`: an optional sign followed by one or more
+    /// `<number><unit>` components with units `h`, `m`, `s`, `ms`, `us`, `ns`.
+    /// Fractions finer than a nanosecond are truncated.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_human_duration(s)
+    }
+}
+
+impl TryFrom<&str> for Duration {
+    type Error = DurationParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Errors parsing a `DateTime` or `OffsetDateTime` from text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The overall layout (separators, missing parts) is not recognized.
+    InvalidFormat,
+    /// The date portion is not a valid date.
+    InvalidDate,
+    /// The time portion is not a valid time.
+    InvalidTime,
+    /// The UTC offset is missing or malformed.
+    InvalidOffset,
+    /// The value is valid but outside the supported range.
+    OutOfRange,
+    /// The text carries a UTC offset other than `Z`, which the UTC-only
+    /// `DateTime` parser rejects; parse it as an [`OffsetDateTime`] or with
+    /// [`DateTime::parse_rfc3339`] instead.
+    UseOffsetDateTime,
+    /// The text contains non-ASCII characters; every supported layout is
+    /// plain ASCII, so such input is rejected before any slicing.
+    NonAscii,
+}
+
+/// Errors formatting with a `%`-style pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatError {
+    /// The pattern ends with a lone `%`.
+    TrailingPercent,
+    /// `%` is followed by a specifier that isn't supported for this type.
+    UnknownSpecifier(char),
+    /// The output writer returned an error.
+    Write,
+}
+
+impl From<fmt::Error> for FormatError {
+    fn from(_: fmt::Error) -> Self {
+        FormatError::Write
+    }
+}
+
+/// Combined UTC date and time (no time zone).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct DateTime {
     pub date: Date,
     pub time: Time,
 }
 
 impl DateTime {
+    /// Earliest representable instant: [`Date::MIN`] at midnight.
+    pub const MIN: DateTime = DateTime {
+        date: Date::MIN,
+        time: Time {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+        },
+    };
+
+    /// Latest representable instant: [`Date::MAX`] at 23:59:59.999999999.
+    pub const MAX: DateTime = DateTime {
+        date: Date::MAX,
+        time: Time {
+            hour: 23,
+            minute: 59,
+            second: 59,
+            nanosecond: 999_999_999,
+        },
+    };
+
     #[inline(always)]
     pub fn new(date: Date, time: Time) -> DateTime {
         DateTime { date, time }
     }
 
+    /// Replace the date, keeping the time of day.
+    #[inline]
+    pub fn with_date(self, date: Date) -> DateTime {
+        DateTime { date, ..self }
+    }
+
+    /// Replace the time of day, keeping the date.
+    #[inline]
+    pub fn with_time(self, time: Time) -> DateTime {
+        DateTime { time, ..self }
+    }
+
+    /// Replace the nanosecond, keeping the rest; `dt.with_nanosecond(0)`
+    /// drops sub-second noise before a comparison.
+    #[inline]
+    pub fn with_nanosecond(self, nanosecond: u32) -> Result<DateTime, TimeError> {
+        Ok(self.with_time(self.time.with_nanosecond(nanosecond)?))
+    }
+
+    /// Year; shorthand for `self.date.year`.
+    #[inline]
+    pub fn year(self) -> i32 {
+        self.date.year
+    }
+
+    /// Month (1..=12).
+    #[inline]
+    pub fn month(self) -> u8 {
+        self.date.month
+    }
+
+    /// Day of month (1..=31).
+    #[inline]
+    pub fn day(self) -> u8 {
+        self.date.day
+    }
+
+    /// Hour (0..=23).
+    #[inline]
+    pub fn hour(self) -> u8 {
+        self.time.hour
+    }
+
+    /// Minute (0..=59).
+    #[inline]
+    pub fn minute(self) -> u8 {
+        self.time.minute
+    }
+
+    /// Second (0..=59).
+    #[inline]
+    pub fn second(self) -> u8 {
+        self.time.second
+    }
+
+    /// Nanosecond of the second.
+    #[inline]
+    pub fn nanosecond(self) -> u32 {
+        self.time.nanosecond
+    }
+
+    /// Day of week; see [`Date::weekday`].
+    #[inline]
+    pub fn weekday(self) -> Weekday {
+        self.date.weekday()
+    }
+
+    /// Day of year; see [`Date::ordinal`].
+    #[inline]
+    pub fn ordinal(self) -> u16 {
+        self.date.ordinal()
+    }
+
     /// Build from Unix timestamp (seconds since 1970-01-01T00:00:00Z)
     /// plus an additional nanoseconds offset (can be negative or >1e9).
     #[inline]
@@ -520,6 +1946,15 @@ impl DateTime {
         Ok(DateTime { date, time })
     }
 
+    /// Build from a date and nanoseconds since midnight, as stored by
+    /// schemas with separate date and nanos-of-day columns.
+    ///
+    /// Returns `OutOfRange` if `nanos_of_day` is a full day or more.
+    pub fn with_date_and_nanos(date: Date, nanos_of_day: u64) -> Result<DateTime, DateError> {
+        let time = Time::from_packed_nanos(nanos_of_day).map_err(|_| DateError::OutOfRange)?;
+        Ok(DateTime { date, time })
+    }
+
     /// Seconds since Unix epoch (1970-01-01T00:00:00Z).
     #[inline]
     pub fn unix_timestamp(self) -> i64 {
@@ -534,12 +1969,205 @@ impl DateTime {
         self.unix_timestamp() as i128 * 1_000_000_000 + self.time.nanosecond as i128
     }
 
+    /// Milliseconds since Unix epoch, rounded toward negative infinity.
+    ///
+    /// Returns `OutOfRange` if the value doesn't fit in an `i64` (beyond
+    /// roughly ±292 million years).
+    #[inline]
+    pub fn unix_timestamp_millis(self) -> Result<i64, DateError> {
+        i64::try_from(self.unix_timestamp_nanos().div_euclid(1_000_000))
+            .map_err(|_| DateError::OutOfRange)
+    }
+
+    /// Microseconds since Unix epoch, rounded toward negative infinity.
+    ///
+    /// Returns `OutOfRange` if the value doesn't fit in an `i64` (beyond
+    /// roughly ±292 thousand years).
+    #[inline]
+    pub fn unix_timestamp_micros(self) -> Result<i64, DateError> {
+        i64::try_from(self.unix_timestamp_nanos().div_euclid(1_000))
+            .map_err(|_| DateError::OutOfRange)
+    }
+
+    /// Build from milliseconds since the Unix epoch.
+    #[inline]
+    pub fn from_unix_timestamp_millis(ms: i64) -> Result<DateTime, DateError> {
+        let nanos = (ms.rem_euclid(1_000) * 1_000_000) as i32;
+        DateTime::from_unix_timestamp(ms.div_euclid(1_000), nanos)
+    }
+
+    /// Build from microseconds since the Unix epoch.
+    #[inline]
+    pub fn from_unix_timestamp_micros(us: i64) -> Result<DateTime, DateError> {
+        let nanos = (us.rem_euclid(1_000_000) * 1_000) as i32;
+        DateTime::from_unix_timestamp(us.div_euclid(1_000_000), nanos)
+    }
+
+    /// Build from fractional seconds since the Unix epoch, as found in JSON
+    /// and scientific data (e.g. `1699228799.5`).
+    ///
+    /// The value is floored to whole seconds and the fraction rounded to the
+    /// nearest nanosecond, so negative values work as expected (`-0.25` is
+    /// 1969-12-31T23:59:59.75Z). An `f64` near present-day timestamps only
+    /// resolves about 240 ns, and beyond 2^53 seconds not even whole
+    /// seconds; digits finer than that are noise from the float. Returns
+    /// `OutOfRange` for NaN, infinities and values outside the `i64` range.
+    pub fn from_unix_timestamp_f64(secs: f64) -> Result<DateTime, DateError> {
+        // 2^63: the first value past `i64::MAX`.
+        const I64_LIMIT: f64 = 9_223_372_036_854_775_808.0;
+        if !secs.is_finite() {
+            return Err(DateError::OutOfRange);
+        }
+        let whole = floor_f64(secs);
+        if !(-I64_LIMIT..I64_LIMIT).contains(&whole) {
+            return Err(DateError::OutOfRange);
+        }
+        // The fraction is in [0, 1); a value rounding up to 1e9 ns carries
+        // into the seconds in `from_unix_timestamp`.
+        let nanos = floor_f64((secs - whole) * 1_000_000_000.0 + 0.5) as i32;
+        DateTime::from_unix_timestamp(whole as i64, nanos)
+    }
+
+    /// Seconds since the Unix epoch as an `f64`, including the fraction.
+    ///
+    /// Subject to the precision limits described in
+    /// [`DateTime::from_unix_timestamp_f64`].
+    pub fn unix_timestamp_f64(self) -> f64 {
+        self.unix_timestamp() as f64 + self.time.nanosecond as f64 / 1_000_000_000.0
+    }
+
+    /// Convert many Unix timestamps (whole seconds) at once.
+    ///
+    /// Equivalent to calling [`DateTime::from_unix_timestamp`] with zero
+    /// nanoseconds on each element, with the output allocated up front.
+    #[cfg(feature = "alloc")]
+    pub fn from_unix_timestamps(secs: &[i64]) -> Vec<Result<DateTime, DateError>> {
+        secs.iter()
+            .map(|&s| DateTime::from_unix_timestamp(s, 0))
+            .collect()
+    }
+
+    /// Write the [`DateTime::unix_timestamp`] of each of `dts` into `out`.
+    ///
+    /// The loop has no fallible steps, so the compiler is free to unroll and
+    /// vectorize it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dts` and `out` have different lengths.
+    pub fn to_unix_timestamps(dts: &[DateTime], out: &mut [i64]) {
+        assert_eq!(dts.len(), out.len(), "input and output lengths differ");
+        for (slot, dt) in out.iter_mut().zip(dts) {
+            *slot = dt.unix_timestamp();
+        }
+    }
+
+    /// Unix seconds as an `i32`, the classic 32-bit `time_t`.
+    ///
+    /// Returns `OutOfRange` outside 1901-12-13T20:45:52Z..=2038-01-19T03:14:07Z.
+    /// Sub-second precision is dropped.
+    pub fn to_unix_i32(self) -> Result<i32, DateError> {
+        i32::try_from(self.unix_timestamp()).map_err(|_| DateError::OutOfRange)
+    }
+
+    /// Unix seconds as a `u32`.
+    ///
+    /// Returns `OutOfRange` before 1970 or after 2106-02-07T06:28:15Z.
+    /// Sub-second precision is dropped.
+    pub fn to_unix_u32(self) -> Result<u32, DateError> {
+        u32::try_from(self.unix_timestamp()).map_err(|_| DateError::OutOfRange)
+    }
+
     /// Add a duration, returning a new `DateTime` (or `OutOfRange` on overflow).
     pub fn add_duration(self, dur: Duration) -> Result<DateTime, DateError> {
-        let t = self.unix_timestamp_nanos() + dur.total_nanos();
-        let secs = t.div_euclid(1_000_000_000);
+        let t = self
+            .unix_timestamp_nanos()
+            .checked_add(dur.total_nanos())
+            .ok_or(DateError::OutOfRange)?;
+        let secs = i64::try_from(t.div_euclid(1_000_000_000)).map_err(|_| DateError::OutOfRange)?;
         let nanos = t.rem_euclid(1_000_000_000);
-        DateTime::from_unix_timestamp(secs as i64, nanos as i32)
+        DateTime::from_unix_timestamp(secs, nanos as i32)
+    }
+
+    /// Instants from `start` toward `end` at fixed `step` intervals, e.g.
+    /// every 15 minutes over a day.
+    ///
+    /// The range is half-open: `end` itself is never yielded. A negative
+    /// `step` walks backward (`start > end`); a zero step, or a step that
+    /// points away from `end`, yields nothing. Iteration also stops if the
+    /// next instant would leave the supported range.
+    pub fn step_by(
+        start: DateTime,
+        end: DateTime,
+        step: Duration,
+    ) -> impl Iterator<Item = DateTime> {
+        let forward = step > Duration::ZERO;
+        let mut cursor = if step == Duration::ZERO {
+            None
+        } else {
+            Some(start)
+        };
+        core::iter::from_fn(move || {
+            let current = cursor?;
+            if (forward && current >= end) || (!forward && current <= end) {
+                cursor = None;
+                return None;
+            }
+            cursor = current.add_duration(step).ok();
+            Some(current)
+        })
+    }
+
+    /// Add a duration, clamping to [`DateTime::MIN`] (`Date::MIN` at
+    /// midnight) or [`DateTime::MAX`] (`Date::MAX` at 23:59:59.999999999)
+    /// instead of returning `OutOfRange`.
+    pub fn saturating_add_duration(self, dur: Duration) -> DateTime {
+        match self.add_duration(dur) {
+            Ok(dt) => dt,
+            Err(_) if dur < Duration::ZERO => DateTime::MIN,
+            Err(_) => DateTime::MAX,
+        }
+    }
+
+    /// Attach a fixed offset, treating `self` as the UTC instant.
+    ///
+    /// Equivalent to [`OffsetDateTime::from_utc`]; the instant is unchanged.
+    pub fn assume_offset(self, offset: UtcOffset) -> OffsetDateTime {
+        OffsetDateTime::from_utc(self, offset)
+    }
+
+    /// Attach the UTC offset (`+00:00`).
+    pub fn assume_utc(self) -> OffsetDateTime {
+        self.assume_offset(UtcOffset::UTC)
+    }
+
+    /// Add a calendar quantity, keeping the time of day.
+    ///
+    /// Days and weeks move the date by whole days; months and years go
+    /// through [`Date::add_months`], so the day is clamped to the length of
+    /// the target month (Jan 31 + 1 month = Feb 28/29, Feb 29 + 1 year =
+    /// Feb 28). Returns `OutOfRange` if the result is not representable.
+    pub fn checked_add_unit(self, unit: CalendarUnit) -> Result<DateTime, DateError> {
+        let date = match unit {
+            CalendarUnit::Days(days) => self.date.add_days(days)?,
+            CalendarUnit::Weeks(weeks) => {
+                let days = weeks.checked_mul(7).ok_or(DateError::OutOfRange)?;
+                self.date.add_days(days)?
+            }
+            CalendarUnit::Months(months) => self.date.add_months(months as i64)?,
+            CalendarUnit::Years(years) => self.date.add_months(years as i64 * 12)?,
+        };
+        Ok(self.with_date(date))
+    }
+
+    /// Same time of day on the next calendar day.
+    pub fn next_day(self) -> Result<DateTime, DateError> {
+        Ok(DateTime::new(self.date.succ()?, self.time))
+    }
+
+    /// Same time of day on the previous calendar day.
+    pub fn previous_day(self) -> Result<DateTime, DateError> {
+        Ok(DateTime::new(self.date.pred()?, self.time))
     }
 
     /// Difference between two instants (self - other).
@@ -548,6 +2176,222 @@ impl DateTime {
         Duration::nanoseconds(self.unix_timestamp_nanos() - other.unix_timestamp_nanos())
     }
 
+    /// Time elapsed from `earlier` to `self`, i.e. `self - earlier`.
+    ///
+    /// The same as [`DateTime::difference`], named for call sites where the
+    /// argument order matters to the reader. Negative if `earlier` is
+    /// actually later; see [`DateTime::checked_duration_since`].
+    #[inline]
+    pub fn duration_since(self, earlier: DateTime) -> Duration {
+        self.difference(earlier)
+    }
+
+    /// Like [`DateTime::duration_since`], but `None` if `earlier` is after
+    /// `self`, mirroring `std::time::Instant::checked_duration_since`.
+    #[inline]
+    pub fn checked_duration_since(self, earlier: DateTime) -> Option<Duration> {
+        if earlier > self {
+            None
+        } else {
+            Some(self.difference(earlier))
+        }
+    }
+
+    /// Restricts `self` to the inclusive range `min..=max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, like [`Ord::clamp`].
+    pub fn clamp(self, min: DateTime, max: DateTime) -> DateTime {
+        assert!(min <= max, "clamp: min is after max");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Whether both instants fall in the same whole second, ignoring the
+    /// nanosecond field.
+    ///
+    /// Useful when comparing timestamps stored at different resolutions:
+    /// both values are truncated (not rounded) before comparing.
+    #[inline]
+    pub fn eq_to_second(self, other: DateTime) -> bool {
+        self.eq_truncated(other, 1_000_000_000)
+    }
+
+    /// Like [`DateTime::eq_to_second`], at millisecond resolution.
+    #[inline]
+    pub fn eq_to_millis(self, other: DateTime) -> bool {
+        self.eq_truncated(other, 1_000_000)
+    }
+
+    /// Like [`DateTime::eq_to_second`], at microsecond resolution.
+    #[inline]
+    pub fn eq_to_micros(self, other: DateTime) -> bool {
+        self.eq_truncated(other, 1_000)
+    }
+
+    fn eq_truncated(self, other: DateTime, unit_nanos: i128) -> bool {
+        self.unix_timestamp_nanos().div_euclid(unit_nanos)
+            == other.unix_timestamp_nanos().div_euclid(unit_nanos)
+    }
+
+    /// `self - reference` in fractional seconds, e.g. for plotting a time
+    /// series against a reference instant.
+    ///
+    /// An `f64` holds 53 bits of mantissa, so the result is exact to the
+    /// nanosecond only for differences up to about 104 days; microsecond
+    /// resolution holds for about 285 years. Use [`DateTime::difference`]
+    /// when exact nanoseconds matter.
+    #[inline]
+    pub fn seconds_since(self, reference: DateTime) -> f64 {
+        self.difference(reference).total_seconds()
+    }
+
+    /// Closest instant in `candidates`, or `None` if the slice is empty.
+    ///
+    /// `candidates` must be sorted ascending; the lookup is a binary search.
+    /// When two candidates are equally close, the earlier one wins.
+    pub fn nearest_in(self, candidates: &[DateTime]) -> Option<DateTime> {
+        let idx = candidates.partition_point(|c| *c < self);
+        let after = candidates.get(idx).copied();
+        let before = idx.checked_sub(1).map(|i| candidates[i]);
+        match (before, after) {
+            (Some(b), Some(a)) => {
+                if self.difference(b) <= a.difference(self) {
+                    Some(b)
+                } else {
+                    Some(a)
+                }
+            }
+            (Some(b), None) => Some(b),
+            (None, a) => a,
+        }
+    }
+
+    /// Parse an RFC 3339 timestamp with any offset (`Z`, `±HH:MM`, ...)
+    /// and return the equivalent UTC instant, discarding the offset.
+    ///
+    /// `FromStr` stays strict and only accepts the `Z` suffix.
+    pub fn parse_rfc3339(s: &str) -> Result<DateTime, ParseError> {
+        parse_offset_datetime(s, None).map(|odt| odt.utc)
+    }
+
+    /// Parse the ISO 8601 basic format "YYYYMMDDTHHMMSS[.fffffffff]Z", e.g.
+    /// `20231105T235959Z`; see [`Date::parse_basic`] and
+    /// [`Time::parse_basic`].
+    pub fn parse_basic(s: &str) -> Result<DateTime, ParseError> {
+        ensure_ascii(s)?;
+        let (date_str, time_str) = s.split_once('T').ok_or(ParseError::InvalidFormat)?;
+        let time_str = time_str
+            .strip_suffix('Z')
+            .or_else(|| time_str.strip_suffix('z'))
+            .ok_or(ParseError::InvalidOffset)?;
+        let date = Date::parse_basic(date_str).map_err(|_| ParseError::InvalidDate)?;
+        let time = Time::parse_basic(time_str).map_err(|_| ParseError::InvalidTime)?;
+        Ok(DateTime { date, time })
+    }
+
+    /// Best-effort parse of the timestamp layouts common in logs, returning
+    /// the UTC instant.
+    ///
+    /// Accepts, after trimming surrounding whitespace:
+    /// - RFC 3339 with `T` or a space (the SQL form) between date and time,
+    ///   and a `Z`, a numeric offset, or no offset (taken as UTC);
+    /// - a date alone (`2024-06-15`), at midnight;
+    /// - a time alone (`10:30:00`, optionally with `Z` or an offset), on
+    ///   1970-01-01.
+    ///
+    /// Prefer the strict parsers when the layout is known.
+    pub fn parse_flexible(s: &str) -> Result<DateTime, ParseError> {
+        let s = s.trim();
+        ensure_ascii(s)?;
+        if s.contains(['T', ' ']) {
+            return parse_offset_datetime(s, Some(UtcOffset::UTC)).map(|odt| odt.utc);
+        }
+        if let Ok(date) = s.parse::<Date>() {
+            let midnight = Time {
+                hour: 0,
+                minute: 0,
+                second: 0,
+                nanosecond: 0,
+            };
+            return Ok(DateTime::new(date, midnight));
+        }
+        if !s.contains(':') {
+            return Err(ParseError::InvalidDate);
+        }
+        let epoch = Date::from_ymd_unchecked(1970, 1, 1);
+        let (time, offset) = match s.parse::<Time>() {
+            Ok(time) => (time, UtcOffset::UTC),
+            Err(_) => Time::parse_with_offset(s).map_err(|_| ParseError::InvalidTime)?,
+        };
+        OffsetDateTime::from_local(epoch, time, offset)
+            .map(|odt| odt.utc)
+            .map_err(|_| ParseError::OutOfRange)
+    }
+
+    /// Format with a `strftime`-style pattern, writing into `out`.
+    ///
+    /// Supported specifiers: `%Y` (year, at least 4 digits), `%y` (last two
+    /// digits of the year), `%m`, `%d`, `%j` (day of year), `%u` (ISO
+    /// weekday, Monday = 1), `%H`, `%M`, `%S`, `%f` (nanoseconds, 9 digits),
+    /// `%F` (`%Y-%m-%d`), `%T` (`%H:%M:%S`) and `%%` for a literal `%`.
+    /// All other text, including `{` and `}`, is copied verbatim.
+    pub fn format_into<W: fmt::Write>(
+        &self,
+        pattern: &str,
+        out: &mut W,
+    ) -> Result<(), FormatError> {
+        format_pattern(out, pattern, Some(self.date), Some(self.time))
+    }
+
+    /// Like [`DateTime::format_into`], returning a `String`.
+    #[cfg(feature = "alloc")]
+    pub fn format(&self, pattern: &str) -> Result<String, FormatError> {
+        let mut out = String::with_capacity(pattern.len() + 16);
+        self.format_into(pattern, &mut out)?;
+        Ok(out)
+    }
+
+    /// The RFC 3339 `Display` form as a `String`, without needing `std`'s
+    /// `ToString`.
+    #[cfg(feature = "alloc")]
+    pub fn to_iso_string(&self) -> String {
+        display_to_string(self, 30)
+    }
+
+    /// RFC 3339 with exactly `digits` fractional digits, e.g.
+    /// `"2023-11-05T23:59:59.000Z"` for `digits == 3`.
+    ///
+    /// Unlike `Display`, trailing zeros are kept, for consumers that require
+    /// a fixed-width fraction. The fraction is truncated as in
+    /// [`Time::format_with_precision_into`]; `digits == 0` omits the decimal
+    /// point and values above 9 are treated as 9.
+    #[cfg(feature = "alloc")]
+    pub fn to_rfc3339_opts(&self, digits: u8) -> String {
+        let mut out = self.date.to_iso_string();
+        out.push('T');
+        out.push_str(&self.time.format_with_precision(digits));
+        out.push('Z');
+        out
+    }
+
+    /// The ISO 8601 basic form, "YYYYMMDDTHHMMSS[.fffffffff]Z"; see
+    /// [`DateTime::parse_basic`].
+    #[cfg(feature = "alloc")]
+    pub fn to_basic_iso(&self) -> String {
+        let mut out = self.date.to_basic_iso();
+        out.push('T');
+        out.push_str(&self.time.to_basic_iso());
+        out.push('Z');
+        out
+    }
+
     /// Get the current UTC `DateTime` (requires `std` feature).
     #[cfg(feature = "std")]
     pub fn now_utc() -> Result<Self, DateError> {
@@ -565,27 +2409,88 @@ impl DateTime {
             }
         }
     }
+
+    /// Time from `self` until now: positive for past instants, negative for
+    /// future ones (requires `std` feature).
+    ///
+    /// The wall-clock counterpart of `std::time::Instant::elapsed`; the
+    /// system clock can jump, so successive calls need not increase.
+    #[cfg(feature = "std")]
+    pub fn elapsed(self) -> Result<Duration, DateError> {
+        Ok(DateTime::now_utc()?.difference(self))
+    }
+
+    /// Get the current UTC `DateTime` at the best resolution the platform
+    /// clock offers (requires `std` feature).
+    ///
+    /// `std::time::SystemTime` already reads `clock_gettime(CLOCK_REALTIME)`
+    /// on unix (nanoseconds) and `GetSystemTimePreciseAsFileTime` on Windows
+    /// (100 ns), so this shares [`DateTime::now_utc`]'s clock rather than
+    /// calling into libc directly. On platforms with a coarser clock the
+    /// result is only as precise as that clock.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn now_utc_high_res() -> Result<Self, DateError> {
+        DateTime::now_utc()
+    }
 }
 
 impl fmt::Display for DateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // ISO 8601 / RFC 3339 UTC: YYYY-MM-DDTHH:MM:SS[.frac]Z
-        write!(f, "{}T{}Z", self.date, self.time)
+        // The alternate form (`{:#}`) omits the `Z` for contexts that
+        // already imply UTC.
+        write!(f, "{}T{}", self.date, self.time)?;
+        if f.alternate() {
+            Ok(())
+        } else {
+            f.write_str("Z")
+        }
+    }
+}
+
+impl fmt::Debug for DateTime {
+    /// `DateTime(2024-01-01T00:00:00Z)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DateTime({})", self)
     }
 }
 
 impl FromStr for DateTime {
-    type Err = ();
+    type Err = ParseError;
 
     /// Parse "YYYY-MM-DDTHH:MM:SS[.fffffffff]Z" (UTC only).
+    ///
+    /// Text ending in a numeric offset such as `+02:00` is reported as
+    /// `UseOffsetDateTime` rather than a generic format error.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s
+        ensure_ascii(s)?;
+        let (date_str, time_str) = s
+            .split_once('T')
+            .or_else(|| s.split_once(' '))
+            .ok_or(ParseError::InvalidFormat)?;
+        let time_str = match time_str
             .strip_suffix('Z')
-            .or_else(|| s.strip_suffix('z'))
-            .ok_or(())?;
-        let (date_str, time_str) = s.split_once('T').or_else(|| s.split_once(' ')).ok_or(())?;
-        let date = date_str.parse::<Date>().map_err(|_| ())?;
-        let time = time_str.parse::<Time>().map_err(|_| ())?;
+            .or_else(|| time_str.strip_suffix('z'))
+        {
+            Some(time_str) => time_str,
+            None => {
+                let has_offset = time_str
+                    .rfind(['+', '-'])
+                    .is_some_and(|idx| parse_rfc3339_offset(&time_str[idx..]).is_ok());
+                return Err(if has_offset {
+                    ParseError::UseOffsetDateTime
+                } else {
+                    ParseError::InvalidOffset
+                });
+            }
+        };
+        let date = date_str
+            .parse::<Date>()
+            .map_err(|_| ParseError::InvalidDate)?;
+        let time = time_str
+            .parse::<Time>()
+            .map_err(|_| ParseError::InvalidTime)?;
         Ok(DateTime { date, time })
     }
 }
@@ -610,19 +2515,52 @@ pub enum UtcOffsetError {
 }
 
 /// Fixed offset from UTC, in seconds (e.g. +02:00).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// An offset parsed from `-00:00` is zero but remembers RFC 3339's "unknown
+/// local offset" convention (see [`UtcOffset::is_unknown_local`]). The marker
+/// is preserved by `Display` but ignored by equality, ordering and hashing.
+#[derive(Debug, Clone, Copy)]
 pub struct UtcOffset {
     seconds: i32,
+    unknown_local: bool,
 }
 
 impl UtcOffset {
+    /// The zero offset, `+00:00`.
+    pub const UTC: UtcOffset = UtcOffset {
+        seconds: 0,
+        unknown_local: false,
+    };
+
+    /// `-00:00`: UTC, with the local offset unknown (RFC 3339 section 4.3).
+    pub const UNKNOWN_LOCAL: UtcOffset = UtcOffset {
+        seconds: 0,
+        unknown_local: true,
+    };
+
     /// Construct from a total number of seconds, roughly in [-24h, +24h].
     pub fn from_seconds(seconds: i32) -> Result<Self, UtcOffsetError> {
         // Rough sanity bounds: [-24h, +24h].
         if !(-86_400..=86_400).contains(&seconds) {
             return Err(UtcOffsetError::OutOfRange);
         }
-        Ok(UtcOffset { seconds })
+        Ok(UtcOffset {
+            seconds,
+            unknown_local: false,
+        })
+    }
+
+    /// Construct from seconds with minimal checking, for `const` offset
+    /// tables: `const IST: UtcOffset = UtcOffset::from_seconds_unchecked(19_800);`
+    ///
+    /// Panics in debug builds if `seconds` is outside the range accepted by
+    /// [`UtcOffset::from_seconds`].
+    pub const fn from_seconds_unchecked(seconds: i32) -> Self {
+        debug_assert!(seconds >= -86_400 && seconds <= 86_400);
+        UtcOffset {
+            seconds,
+            unknown_local: false,
+        }
     }
 
     /// Construct from hours and minutes, with `sign_positive` sign.
@@ -643,6 +2581,19 @@ impl UtcOffset {
         Self::from_seconds(total)
     }
 
+    /// Construct from a signed whole number of hours in `-12..=14`.
+    ///
+    /// For example, `from_whole_hours(-5)` => -05:00.
+    pub fn from_whole_hours(hours: i8) -> Result<Self, UtcOffsetError> {
+        if !(-12..=14).contains(&hours) {
+            return Err(UtcOffsetError::OutOfRange);
+        }
+        Ok(UtcOffset {
+            seconds: hours as i32 * 3600,
+            unknown_local: false,
+        })
+    }
+
     #[inline(always)]
     pub fn as_seconds(self) -> i32 {
         self.seconds
@@ -652,6 +2603,66 @@ impl UtcOffset {
     pub fn is_utc(self) -> bool {
         self.seconds == 0
     }
+
+    /// True if this offset was written as `-00:00`: the time is known in UTC
+    /// but the local offset is not. [`UtcOffset::is_utc`] is also true.
+    #[inline(always)]
+    pub fn is_unknown_local(self) -> bool {
+        self.unknown_local
+    }
+
+    /// True if the offset has no seconds component (e.g. +05:30, not +05:30:15).
+    #[inline(always)]
+    pub fn is_whole_minutes(self) -> bool {
+        self.seconds % 60 == 0
+    }
+
+    /// True if the offset is a whole number of hours (e.g. +02:00, not +05:30).
+    #[inline(always)]
+    pub fn is_whole_hours(self) -> bool {
+        self.seconds % 3600 == 0
+    }
+
+    /// Shift by `delta_seconds`, clamping to the `±86400` range accepted by
+    /// [`UtcOffset::from_seconds`].
+    ///
+    /// The result is a plain offset: a `-00:00` input does not keep its
+    /// unknown-local marker.
+    pub fn saturating_add(self, delta_seconds: i32) -> UtcOffset {
+        UtcOffset {
+            seconds: self
+                .seconds
+                .saturating_add(delta_seconds)
+                .clamp(-86_400, 86_400),
+            unknown_local: false,
+        }
+    }
+}
+
+impl core::ops::Neg for UtcOffset {
+    type Output = UtcOffset;
+
+    /// Flip the sign (`+05:30` becomes `-05:30`); always in range.
+    fn neg(self) -> UtcOffset {
+        UtcOffset {
+            seconds: -self.seconds,
+            unknown_local: false,
+        }
+    }
+}
+
+impl PartialEq for UtcOffset {
+    fn eq(&self, other: &Self) -> bool {
+        self.seconds == other.seconds
+    }
+}
+
+impl Eq for UtcOffset {}
+
+impl Hash for UtcOffset {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.seconds.hash(state);
+    }
 }
 
 impl PartialOrd for UtcOffset {
@@ -669,7 +2680,11 @@ impl Ord for UtcOffset {
 impl fmt::Display for UtcOffset {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut secs = self.seconds;
-        let sign = if secs >= 0 { '+' } else { '-' };
+        let sign = if secs >= 0 && !self.unknown_local {
+            '+'
+        } else {
+            '-'
+        };
         if secs < 0 {
             secs = -secs;
         }
@@ -680,7 +2695,10 @@ impl fmt::Display for UtcOffset {
 }
 
 /// Date-time with a fixed offset from UTC (RFC 3339-style).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// The offset is fixed: there is no time zone database and hence no DST.
+/// See [`OffsetDateTime::is_dst`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct OffsetDateTime {
     /// Instant in UTC.
     pub utc: DateTime,
@@ -694,6 +2712,20 @@ impl OffsetDateTime {
         OffsetDateTime { utc, offset }
     }
 
+    /// Construct from a Unix timestamp, shown at `offset`.
+    ///
+    /// `secs` and `nanos` are normalized as in
+    /// [`DateTime::from_unix_timestamp`]; the offset only affects the local
+    /// view, not the instant.
+    pub fn from_unix_timestamp(
+        secs: i64,
+        nanos: i32,
+        offset: UtcOffset,
+    ) -> Result<Self, DateError> {
+        let utc = DateTime::from_unix_timestamp(secs, nanos)?;
+        Ok(OffsetDateTime { utc, offset })
+    }
+
     /// Construct from a local date+time with offset, converting to UTC.
     pub fn from_local(date: Date, time: Time, offset: UtcOffset) -> Result<Self, DateError> {
         let local = DateTime::new(date, time);
@@ -701,12 +2733,92 @@ impl OffsetDateTime {
         Ok(OffsetDateTime { utc, offset })
     }
 
+    /// Parse SQL-style timestamp text, such as Postgres `timestamptz` output:
+    /// `"2023-11-05 23:59:59.123456+02"`.
+    ///
+    /// Accepts a space or `T` separator and any offset form understood by
+    /// [`parse_rfc3339_offset`] (including hour-only `+02`). Text without an
+    /// offset is read as local time in `default_offset`.
+    pub fn from_sql_timestamp(s: &str, default_offset: UtcOffset) -> Result<Self, ParseError> {
+        parse_offset_datetime(s, Some(default_offset))
+    }
+
     /// Local date/time as seen in this offset.
     pub fn to_local(&self) -> Result<DateTime, DateError> {
         self.utc
             .add_duration(Duration::seconds(self.offset.as_seconds() as i64))
     }
 
+    /// Local calendar date as seen in this offset.
+    pub fn local_date(&self) -> Result<Date, DateError> {
+        self.to_local().map(|dt| dt.date)
+    }
+
+    /// Local time of day as seen in this offset.
+    pub fn local_time(&self) -> Result<Time, DateError> {
+        self.to_local().map(|dt| dt.time)
+    }
+
+    /// Local year.
+    pub fn year(&self) -> Result<i32, DateError> {
+        self.local_date().map(|d| d.year)
+    }
+
+    /// Local month (1..=12).
+    pub fn month(&self) -> Result<u8, DateError> {
+        self.local_date().map(|d| d.month)
+    }
+
+    /// Local day of month (1..=31).
+    pub fn day(&self) -> Result<u8, DateError> {
+        self.local_date().map(|d| d.day)
+    }
+
+    /// Local hour (0..=23).
+    pub fn hour(&self) -> Result<u8, DateError> {
+        self.local_time().map(|t| t.hour)
+    }
+
+    /// Local minute (0..=59).
+    pub fn minute(&self) -> Result<u8, DateError> {
+        self.local_time().map(|t| t.minute)
+    }
+
+    /// Local second (0..=59).
+    pub fn second(&self) -> Result<u8, DateError> {
+        self.local_time().map(|t| t.second)
+    }
+
+    /// Nanosecond of the second; offsets are whole seconds, so this is the
+    /// same locally and in UTC and never fails.
+    pub fn nanosecond(&self) -> u32 {
+        self.utc.time.nanosecond
+    }
+
+    /// Local day of week.
+    pub fn weekday(&self) -> Result<Weekday, DateError> {
+        self.local_date().map(Date::weekday)
+    }
+
+    /// Whether daylight saving time is in effect. Always `false`.
+    ///
+    /// `OffsetDateTime` carries a fixed offset, not a time zone, so there are
+    /// no DST rules to consult. Code that needs DST awareness must resolve
+    /// the zone's offset elsewhere and construct the value with it.
+    #[inline]
+    pub fn is_dst(&self) -> bool {
+        false
+    }
+
+    /// Fold marker for repeated wall-clock times. Always `0` (unambiguous).
+    ///
+    /// Mirrors Python's `datetime.fold`. With a fixed offset every local
+    /// time maps to exactly one instant, so there is never a second fold.
+    #[inline]
+    pub fn fold(&self) -> u8 {
+        0
+    }
+
     /// Seconds since Unix epoch (1970-01-01T00:00:00Z).
     #[inline(always)]
     pub fn unix_timestamp(&self) -> i64 {
@@ -719,6 +2831,16 @@ impl OffsetDateTime {
         self.utc.unix_timestamp_nanos()
     }
 
+    /// Unix seconds as an `i32`; see [`DateTime::to_unix_i32`].
+    pub fn to_unix_i32(&self) -> Result<i32, DateError> {
+        self.utc.to_unix_i32()
+    }
+
+    /// Unix seconds as a `u32`; see [`DateTime::to_unix_u32`].
+    pub fn to_unix_u32(&self) -> Result<u32, DateError> {
+        self.utc.to_unix_u32()
+    }
+
     /// Add a duration, keeping the same offset.
     pub fn add_duration(&self, dur: Duration) -> Result<Self, DateError> {
         let utc = self.utc.add_duration(dur)?;
@@ -728,21 +2850,110 @@ impl OffsetDateTime {
         })
     }
 
+    /// Add a duration, keeping the same offset and clamping the UTC instant
+    /// as in [`DateTime::saturating_add_duration`].
+    ///
+    /// Near the extremes the local time may not be representable even
+    /// though the UTC instant is; [`OffsetDateTime::to_local`] then errors.
+    pub fn saturating_add_duration(&self, dur: Duration) -> Self {
+        OffsetDateTime {
+            utc: self.utc.saturating_add_duration(dur),
+            offset: self.offset,
+        }
+    }
+
     /// Difference between two instants (self - other).
     #[inline(always)]
     pub fn difference(&self, other: OffsetDateTime) -> Duration {
         self.utc.difference(other.utc)
     }
+
+    /// `self - reference` in fractional seconds; see
+    /// [`DateTime::seconds_since`] for the `f64` precision limits.
+    #[inline]
+    pub fn seconds_since(&self, reference: OffsetDateTime) -> f64 {
+        self.utc.seconds_since(reference.utc)
+    }
+
+    /// The same instant at offset zero.
+    ///
+    /// Two values for the same instant with different offsets are unequal
+    /// (and hash differently); after normalizing they compare and hash equal.
+    #[inline]
+    pub fn normalized_utc(self) -> OffsetDateTime {
+        OffsetDateTime {
+            utc: self.utc,
+            offset: UtcOffset::UTC,
+        }
+    }
+
+    /// Whether this value and a UTC `DateTime` refer to the same instant.
+    ///
+    /// The offset is ignored; only the underlying UTC instant is compared.
+    #[inline]
+    pub fn same_instant(&self, dt: DateTime) -> bool {
+        self.utc == dt
+    }
+
+    /// Compare the instant of this value with a UTC `DateTime`.
+    ///
+    /// The offset is ignored; only the underlying UTC instant is compared.
+    #[inline]
+    pub fn cmp_instant(&self, dt: DateTime) -> Ordering {
+        self.utc.cmp(&dt)
+    }
+
+    /// Compare local wall-clock readings, ignoring the offsets.
+    ///
+    /// Unlike `Ord`, which orders by instant, this treats 09:00+02:00 and
+    /// 09:00-05:00 as equal and 09:00+02:00 and 07:00Z as different. Errors
+    /// if either local datetime is out of range.
+    pub fn cmp_local(&self, other: &OffsetDateTime) -> Result<Ordering, DateError> {
+        Ok(self.to_local()?.cmp(&other.to_local()?))
+    }
+
+    /// Sort key for local wall-clock order: nanoseconds from
+    /// 1970-01-01T00:00:00 to the local reading.
+    ///
+    /// Equals `to_local()?.unix_timestamp_nanos()` when that succeeds, but
+    /// never fails, so it works with `sort_by_key` even at the edges of the
+    /// supported range.
+    #[inline]
+    pub fn local_key(&self) -> i128 {
+        self.utc.unix_timestamp_nanos() + self.offset.as_seconds() as i128 * 1_000_000_000
+    }
+
+    /// Whether both values fall on the same local calendar date, each read
+    /// in its own offset.
+    ///
+    /// This is what a day-view calendar groups by, and differs from
+    /// comparing UTC dates: 2024-06-16T00:30+02:00 and
+    /// 2024-06-15T20:00-02:00 share a UTC date but not a local one. Built on
+    /// [`OffsetDateTime::local_key`], so it never fails.
+    pub fn is_same_local_date(&self, other: &OffsetDateTime) -> bool {
+        const NANOS_PER_DAY: i128 = 86_400 * 1_000_000_000;
+        self.local_key().div_euclid(NANOS_PER_DAY) == other.local_key().div_euclid(NANOS_PER_DAY)
+    }
+
+    /// The RFC 3339 `Display` form as a `String`, without needing `std`'s
+    /// `ToString`.
+    #[cfg(feature = "alloc")]
+    pub fn to_iso_string(&self) -> String {
+        display_to_string(self, 35)
+    }
 }
 
 impl fmt::Display for OffsetDateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // RFC 3339: local "YYYY-MM-DDTHH:MM:SS[.frac]" + offset.
-        let local = self
-            .to_local()
-            .expect("OffsetDateTime local representation out of range");
+        // If the local representation is out of range, fall back to the
+        // UTC instant so formatting never panics.
+        let local = match self.to_local() {
+            Ok(local) => local,
+            Err(_) => return write!(f, "{}", self.utc),
+        };
         write!(f, "{}T{}", local.date, local.time)?;
-        if self.offset.is_utc() {
+        if self.offset.is_utc() && !self.offset.is_unknown_local() {
             write!(f, "Z")
         } else {
             write!(f, "{}", self.offset)
@@ -750,27 +2961,31 @@ impl fmt::Display for OffsetDateTime {
     }
 }
 
+impl fmt::Debug for OffsetDateTime {
+    /// `OffsetDateTime(2024-01-01T02:00:00+02:00)`; if the local time is out
+    /// of range, the UTC instant and offset are shown separately.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.to_local().is_ok() {
+            write!(f, "OffsetDateTime({})", self)
+        } else {
+            write!(
+                f,
+                "OffsetDateTime(utc={}, offset={})",
+                self.utc, self.offset
+            )
+        }
+    }
+}
+
 impl FromStr for OffsetDateTime {
     type Err = ();
 
     /// Parse RFC 3339-style:
     /// "YYYY-MM-DDTHH:MM:SS[.fffffffff][Z|±HH:MM]"
+    ///
+    /// The offset may also be written as `±HHMM` or hour-only `±HH`.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
-        let (date_part, rest) = s.split_once('T').or_else(|| s.split_once(' ')).ok_or(())?;
-        let date: Date = date_part.parse().map_err(|_| ())?;
-
-        // Parse time + offset.
-        let (time_part, offset_part) = if rest.ends_with('Z') || rest.ends_with('z') {
-            (&rest[..rest.len() - 1], "Z")
-        } else {
-            let idx = rest.rfind(['+', '-']).ok_or(())?;
-            (&rest[..idx], &rest[idx..])
-        };
-
-        let time: Time = time_part.parse().map_err(|_| ())?;
-        let offset = parse_rfc3339_offset(offset_part).map_err(|_| ())?;
-        OffsetDateTime::from_local(date, time, offset).map_err(|_| ())
+        parse_offset_datetime(s, None).map_err(|_| ())
     }
 }
 
@@ -786,6 +3001,54 @@ impl Ord for OffsetDateTime {
     }
 }
 
+// Cross-type comparisons treat `DateTime` as UTC and ignore the offset.
+
+impl PartialEq<DateTime> for OffsetDateTime {
+    fn eq(&self, other: &DateTime) -> bool {
+        self.same_instant(*other)
+    }
+}
+
+impl PartialEq<OffsetDateTime> for DateTime {
+    fn eq(&self, other: &OffsetDateTime) -> bool {
+        other.same_instant(*self)
+    }
+}
+
+impl PartialOrd<DateTime> for OffsetDateTime {
+    fn partial_cmp(&self, other: &DateTime) -> Option<Ordering> {
+        Some(self.cmp_instant(*other))
+    }
+}
+
+impl PartialOrd<OffsetDateTime> for DateTime {
+    fn partial_cmp(&self, other: &OffsetDateTime) -> Option<Ordering> {
+        Some(other.cmp_instant(*self).reverse())
+    }
+}
+
+// ===== Bulk parsing =====
+
+/// Parse each of `lines` with `FromStr`, keeping one result per item, e.g.
+/// `parse_all::<DateTime>(column.iter().copied())`.
+///
+/// Failures don't stop the scan; see [`try_parse_all`] for a variant that
+/// stops at the first error.
+#[cfg(feature = "alloc")]
+pub fn parse_all<'a, T: FromStr>(
+    lines: impl IntoIterator<Item = &'a str>,
+) -> Vec<Result<T, T::Err>> {
+    lines.into_iter().map(str::parse).collect()
+}
+
+/// Parse each of `lines` with `FromStr`, returning the first error, if any.
+#[cfg(feature = "alloc")]
+pub fn try_parse_all<'a, T: FromStr>(
+    lines: impl IntoIterator<Item = &'a str>,
+) -> Result<Vec<T>, T::Err> {
+    lines.into_iter().map(str::parse).collect()
+}
+
 // ===== Internal helpers =====
 
 const POW10_U32: [u32; 10] = [
@@ -860,16 +3123,16 @@ fn parse_u32_bytes(bytes: &[u8], max: u32) -> Option<u32> {
     Some(val)
 }
 
+/// Parse fractional-second digits into nanoseconds.
+///
+/// Digits beyond the ninth are validated but truncated (not rounded).
 fn parse_fraction_nanos(bytes: &[u8]) -> Option<u32> {
-    let len = bytes.len();
-    if len == 0 || len > 9 {
+    if bytes.is_empty() || !bytes.iter().all(u8::is_ascii_digit) {
         return None;
     }
+    let len = bytes.len().min(9);
     let mut val: u32 = 0;
-    for &b in bytes {
-        if !b.is_ascii_digit() {
-            return None;
-        }
+    for &b in &bytes[..len] {
         val = val * 10 + (b - b'0') as u32;
     }
     let scale = 9 - len;
@@ -883,6 +3146,9 @@ pub enum Rfc3339OffsetError {
     OutOfRange,
 }
 
+/// Parse `Z`, `±HH:MM`, `±HHMM` or `±HH`.
+///
+/// `-00:00` (in any of these forms) yields [`UtcOffset::UNKNOWN_LOCAL`].
 pub fn parse_rfc3339_offset(s: &str) -> Result<UtcOffset, Rfc3339OffsetError> {
     if s == "Z" || s == "z" {
         return UtcOffset::from_seconds(0).map_err(|_| Rfc3339OffsetError::OutOfRange);
@@ -931,30 +3197,492 @@ pub fn parse_rfc3339_offset(s: &str) -> Result<UtcOffset, Rfc3339OffsetError> {
     } else {
         parse_u32_bytes(m_bytes, 99).ok_or(Rfc3339OffsetError::InvalidFormat)? as u8
     };
+    if !sign_positive && hours == 0 && minutes == 0 {
+        return Ok(UtcOffset::UNKNOWN_LOCAL);
+    }
     UtcOffset::from_hours_minutes(sign_positive, hours, minutes)
         .map_err(|_| Rfc3339OffsetError::OutOfRange)
 }
 
-fn is_leap_year(year: i32) -> bool {
+/// Expand a `%`-style pattern; specifiers for a missing date or time part
+/// are reported as unknown.
+fn format_pattern<W: fmt::Write>(
+    out: &mut W,
+    pattern: &str,
+    date: Option<Date>,
+    time: Option<Time>,
+) -> Result<(), FormatError> {
+    let mut rest = pattern;
+    while let Some(pos) = rest.find('%') {
+        out.write_str(&rest[..pos])?;
+        let mut chars = rest[pos + 1..].chars();
+        let spec = chars.next().ok_or(FormatError::TrailingPercent)?;
+        match (spec, date, time) {
+            ('%', _, _) => out.write_char('%')?,
+            ('Y', Some(d), _) => write!(out, "{:04}", d.year)?,
+            ('y', Some(d), _) => write!(out, "{:02}", d.year.rem_euclid(100))?,
+            ('m', Some(d), _) => write!(out, "{:02}", d.month)?,
+            ('d', Some(d), _) => write!(out, "{:02}", d.day)?,
+            ('j', Some(d), _) => write!(out, "{:03}", d.ordinal())?,
+            ('u', Some(d), _) => write!(out, "{}", d.weekday().number_from_monday())?,
+            ('F', Some(d), _) => write!(out, "{}", d)?,
+            ('H', _, Some(t)) => write!(out, "{:02}", t.hour)?,
+            ('M', _, Some(t)) => write!(out, "{:02}", t.minute)?,
+            ('S', _, Some(t)) => write!(out, "{:02}", t.second)?,
+            ('f', _, Some(t)) => write!(out, "{:09}", t.nanosecond)?,
+            ('T', _, Some(t)) => write!(out, "{:02}:{:02}:{:02}", t.hour, t.minute, t.second)?,
+            _ => return Err(FormatError::UnknownSpecifier(spec)),
+        }
+        rest = chars.as_str();
+    }
+    out.write_str(rest)?;
+    Ok(())
+}
+
+/// Inverse of [`format_pattern`] for the date specifiers `%Y`, `%y`, `%m`,
+/// `%d` and `%%`.
+fn parse_date_pattern(s: &str, pattern: &str) -> Result<Date, ParseError> {
+    let mut input = s.as_bytes();
+    let mut pattern = pattern.as_bytes();
+    let (mut year, mut month, mut day) = (None, None, None);
+    while let Some((&p, rest)) = pattern.split_first() {
+        pattern = rest;
+        if p != b'%' {
+            input = input.strip_prefix(&[p]).ok_or(ParseError::InvalidFormat)?;
+            continue;
+        }
+        let (&spec, rest) = pattern.split_first().ok_or(ParseError::InvalidFormat)?;
+        pattern = rest;
+        match spec {
+            b'%' => input = input.strip_prefix(b"%").ok_or(ParseError::InvalidFormat)?,
+            b'Y' => {
+                // Directly followed by another numeric field ("%Y%m%d"), the
+                // year can't be delimited by its digits alone: take four.
+                let max_digits = if matches!(pattern, [b'%', b'Y' | b'y' | b'm' | b'd', ..]) {
+                    4
+                } else {
+                    usize::MAX
+                };
+                let sign = matches!(input.first(), Some(b'+' | b'-')) as usize;
+                let len = sign
+                    + input[sign..]
+                        .iter()
+                        .take(max_digits)
+                        .take_while(|b| b.is_ascii_digit())
+                        .count();
+                let (digits, rest) = input.split_at(len);
+                year = Some(parse_i32_bytes(digits).ok_or(ParseError::InvalidFormat)?);
+                input = rest;
+            }
+            b'y' | b'm' | b'd' => {
+                if input.len() < 2 {
+                    return Err(ParseError::InvalidFormat);
+                }
+                let (digits, rest) = input.split_at(2);
+                let value = parse_u32_bytes(digits, 99).ok_or(ParseError::InvalidFormat)? as u8;
+                match spec {
+                    b'y' => year = Some(expand_two_digit_year(value)),
+                    b'm' => month = Some(value),
+                    _ => day = Some(value),
+                }
+                input = rest;
+            }
+            _ => return Err(ParseError::InvalidFormat),
+        }
+    }
+    if !input.is_empty() {
+        return Err(ParseError::InvalidFormat);
+    }
+    match (year, month, day) {
+        (Some(y), Some(m), Some(d)) => Date::from_ymd(y, m, d).map_err(|_| ParseError::InvalidDate),
+        _ => Err(ParseError::InvalidFormat),
+    }
+}
+
+/// POSIX `%y` window: `69..=99` → 1969-1999, `00..=68` → 2000-2068.
+const fn expand_two_digit_year(yy: u8) -> i32 {
+    if yy >= 69 {
+        1900 + yy as i32
+    } else {
+        2000 + yy as i32
+    }
+}
+
+/// `f64::floor` for `no_std`; exact for every finite value.
+fn floor_f64(x: f64) -> f64 {
+    // Values this large have no fractional part.
+    if x.abs() >= 4_503_599_627_370_496.0 {
+        return x;
+    }
+    let t = x as i64 as f64;
+    if t > x {
+        t - 1.0
+    } else {
+        t
+    }
+}
+
+/// Round `x` to the nearest integer, ties away from zero, for `no_std`.
+fn round_f64(x: f64) -> f64 {
+    let magnitude = x.abs();
+    let whole = floor_f64(magnitude);
+    // Exact: `whole` is either zero or within a factor of two of `magnitude`.
+    let rounded = if magnitude - whole >= 0.5 {
+        whole + 1.0
+    } else {
+        whole
+    };
+    if x < 0.0 {
+        -rounded
+    } else {
+        rounded
+    }
+}
+
+/// Shared body of the `Duration::from_*_f64` constructors.
+fn duration_from_f64(value: f64, nanos_per_unit: f64) -> Result<Duration, DurationError> {
+    // 2^127: the first magnitude outside `i128`.
+    const I128_LIMIT: f64 = 170_141_183_460_469_231_731_687_303_715_884_105_728.0;
+    if !value.is_finite() {
+        return Err(DurationError::NotFinite);
+    }
+    let nanos = round_f64(value * nanos_per_unit);
+    if !(-I128_LIMIT..I128_LIMIT).contains(&nanos) {
+        return Err(DurationError::Overflow);
+    }
+    Ok(Duration::nanoseconds(nanos as i128))
+}
+
+/// Render a `Display` value into a `String` via `core::fmt::Write`.
+#[cfg(feature = "alloc")]
+fn display_to_string(value: &dyn fmt::Display, capacity: usize) -> String {
+    use core::fmt::Write;
+    let mut out = String::with_capacity(capacity);
+    let _ = write!(out, "{}", value);
+    out
+}
+
+/// Nanoseconds as fraction digits with trailing zeros dropped, e.g.
+/// `500_000_000` → `(5, 1)`; returns the value and its zero-padded width.
+#[cfg(feature = "alloc")]
+fn trim_fraction_nanos(nanos: u32) -> (u32, usize) {
+    let (mut frac, mut width) = (nanos, 9);
+    while width > 1 && frac % 10 == 0 {
+        frac /= 10;
+        width -= 1;
+    }
+    (frac, width)
+}
+
+/// Write `int` followed by `.frac` (zero-padded to `digits`, trailing
+/// zeros trimmed); the fraction is omitted entirely when zero.
+fn write_decimal(f: &mut fmt::Formatter<'_>, int: u128, frac: u32, digits: usize) -> fmt::Result {
+    write!(f, "{}", int)?;
+    if frac == 0 {
+        return Ok(());
+    }
+    let mut buf = [b'0'; 9];
+    let mut v = frac;
+    for i in (0..digits).rev() {
+        buf[i] = b'0' + (v % 10) as u8;
+        v /= 10;
+    }
+    let mut end = digits;
+    while end > 0 && buf[end - 1] == b'0' {
+        end -= 1;
+    }
+    let frac_str = core::str::from_utf8(&buf[..end]).unwrap_or("0");
+    write!(f, ".{}", frac_str)
+}
+
+fn duration_unit_nanos(unit: &[u8]) -> Option<u128> {
+    match unit {
+        b"ns" => Some(1),
+        b"us" => Some(1_000),
+        b"ms" => Some(1_000_000),
+        b"s" => Some(1_000_000_000),
+        b"m" => Some(60 * 1_000_000_000),
+        b"h" => Some(3_600 * 1_000_000_000),
+        _ => None,
+    }
+}
+
+/// Go-style units: [`duration_unit_nanos`] plus `µs`/`μs` and `d`.
+fn humantime_unit_nanos(unit: &[u8]) -> Option<u128> {
+    match unit {
+        // `µs` (U+00B5 MICRO SIGN) and `μs` (U+03BC GREEK SMALL LETTER MU).
+        b"\xc2\xb5s" | b"\xce\xbcs" => Some(1_000),
+        b"d" => Some(86_400 * 1_000_000_000),
+        _ => duration_unit_nanos(unit),
+    }
+}
+
+fn parse_human_duration(s: &str) -> Result<Duration, DurationParseError> {
+    parse_unit_duration(s, duration_unit_nanos)
+}
+
+/// Sign followed by `<number><unit>` components, with units looked up in
+/// `unit_nanos`.
+fn parse_unit_duration(
+    s: &str,
+    unit_nanos: fn(&[u8]) -> Option<u128>,
+) -> Result<Duration, DurationParseError> {
+    let (neg, mut rest) = split_duration_sign(s)?;
+    if rest == b"0" {
+        return Ok(Duration::ZERO);
+    }
+
+    let mut total: u128 = 0;
+    while !rest.is_empty() {
+        let (int_digits, frac_digits, after) = split_decimal(rest)?;
+        let unit_len = after
+            .iter()
+            .take_while(|b| !b.is_ascii_digit() && **b != b'.')
+            .count();
+        let (unit, tail) = after.split_at(unit_len);
+        let unit = unit_nanos(unit).ok_or(DurationParseError::InvalidUnit)?;
+        total = total
+            .checked_add(scaled_nanos(int_digits, frac_digits, unit)?)
+            .ok_or(DurationParseError::Overflow)?;
+        rest = tail;
+    }
+    signed_duration(neg, total)
+}
+
+fn parse_iso8601_duration(s: &str) -> Result<Duration, DurationParseError> {
+    let (neg, rest) = split_duration_sign(s)?;
+    let mut rest = match rest.split_first() {
+        Some((b'P', tail)) => tail,
+        _ => return Err(DurationParseError::InvalidUnit),
+    };
+
+    // Designators must appear in this order, each at most once.
+    const ORDER: [(bool, u8, u128); 5] = [
+        (false, b'W', 7 * 86_400 * 1_000_000_000),
+        (false, b'D', 86_400 * 1_000_000_000),
+        (true, b'H', 3_600 * 1_000_000_000),
+        (true, b'M', 60 * 1_000_000_000),
+        (true, b'S', 1_000_000_000),
+    ];
+    let mut next = 0;
+    let mut in_time = false;
+    let mut seen_fraction = false;
+    let mut seen_component = false;
+    let mut total: u128 = 0;
+    while !rest.is_empty() {
+        if rest[0] == b'T' {
+            if in_time || rest.len() == 1 {
+                return Err(DurationParseError::InvalidUnit);
+            }
+            in_time = true;
+            rest = &rest[1..];
+            continue;
+        }
+        // Only the smallest component may carry a fraction.
+        if seen_fraction {
+            return Err(DurationParseError::InvalidNumber);
+        }
+        let (int_digits, frac_digits, after) = split_decimal(rest)?;
+        let (&designator, tail) = after.split_first().ok_or(DurationParseError::InvalidUnit)?;
+        let offset = ORDER[next..]
+            .iter()
+            .position(|&(time, d, _)| time == in_time && d == designator)
+            .ok_or(DurationParseError::InvalidUnit)?;
+        let unit_nanos = ORDER[next + offset].2;
+        next += offset + 1;
+
+        total = total
+            .checked_add(scaled_nanos(int_digits, frac_digits, unit_nanos)?)
+            .ok_or(DurationParseError::Overflow)?;
+        seen_fraction = !frac_digits.is_empty();
+        seen_component = true;
+        rest = tail;
+    }
+    if !seen_component {
+        return Err(DurationParseError::InvalidNumber);
+    }
+    signed_duration(neg, total)
+}
+
+/// Strip an optional leading sign, rejecting input with nothing after it.
+fn split_duration_sign(s: &str) -> Result<(bool, &[u8]), DurationParseError> {
+    let bytes = s.as_bytes();
+    let (neg, rest) = match bytes.first() {
+        Some(b'-') => (true, &bytes[1..]),
+        Some(b'+') => (false, &bytes[1..]),
+        _ => (false, bytes),
+    };
+    if rest.is_empty() {
+        return Err(DurationParseError::Empty);
+    }
+    Ok((neg, rest))
+}
+
+/// Integer digits, fraction digits and the unparsed remainder.
+type DecimalParts<'a> = (&'a [u8], &'a [u8], &'a [u8]);
+
+/// Split a leading `int[.frac]` number into its digits and the remainder.
+fn split_decimal(bytes: &[u8]) -> Result<DecimalParts<'_>, DurationParseError> {
+    let int_len = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+    let (int_digits, after) = bytes.split_at(int_len);
+    let (frac_digits, after) = match after.split_first() {
+        Some((b'.', tail)) => {
+            let frac_len = tail.iter().take_while(|b| b.is_ascii_digit()).count();
+            if frac_len == 0 {
+                return Err(DurationParseError::InvalidNumber);
+            }
+            tail.split_at(frac_len)
+        }
+        _ => (&[][..], after),
+    };
+    if int_digits.is_empty() && frac_digits.is_empty() {
+        return Err(DurationParseError::InvalidNumber);
+    }
+    Ok((int_digits, frac_digits, after))
+}
+
+/// `int.frac` units of `unit_nanos` each, truncated to whole nanoseconds.
+fn scaled_nanos(
+    int_digits: &[u8],
+    frac_digits: &[u8],
+    unit_nanos: u128,
+) -> Result<u128, DurationParseError> {
+    let mut int: u128 = 0;
+    for &b in int_digits {
+        int = int
+            .checked_mul(10)
+            .and_then(|v| v.checked_add((b - b'0') as u128))
+            .ok_or(DurationParseError::Overflow)?;
+    }
+    // Digits beyond what can affect whole nanoseconds are ignored.
+    let mut frac: u128 = 0;
+    let mut scale: u128 = 1;
+    for &b in frac_digits.iter().take(20) {
+        frac = frac * 10 + (b - b'0') as u128;
+        scale *= 10;
+    }
+    int.checked_mul(unit_nanos)
+        .and_then(|v| v.checked_add(frac * unit_nanos / scale))
+        .ok_or(DurationParseError::Overflow)
+}
+
+fn signed_duration(neg: bool, total: u128) -> Result<Duration, DurationParseError> {
+    let nanos = if neg {
+        0i128
+            .checked_sub_unsigned(total)
+            .ok_or(DurationParseError::Overflow)?
+    } else {
+        i128::try_from(total).map_err(|_| DurationParseError::Overflow)?
+    };
+    Ok(Duration { nanos })
+}
+
+/// Parse `date[T ]time[offset]`; a missing offset falls back to
+/// `default_offset`, or is an error when that is `None`.
+fn parse_offset_datetime(
+    s: &str,
+    default_offset: Option<UtcOffset>,
+) -> Result<OffsetDateTime, ParseError> {
+    let s = s.trim();
+    ensure_ascii(s)?;
+    let (date_part, rest) = s
+        .split_once('T')
+        .or_else(|| s.split_once(' '))
+        .ok_or(ParseError::InvalidFormat)?;
+    let date: Date = date_part.parse().map_err(|_| ParseError::InvalidDate)?;
+
+    // Parse time + offset.
+    let (time_part, offset_part) = if rest.ends_with('Z') || rest.ends_with('z') {
+        (&rest[..rest.len() - 1], Some("Z"))
+    } else if let Some(idx) = rest.rfind(['+', '-']) {
+        (&rest[..idx], Some(&rest[idx..]))
+    } else if default_offset.is_some() {
+        (rest, None)
+    } else {
+        return Err(ParseError::InvalidOffset);
+    };
+
+    let time: Time = time_part.parse().map_err(|_| ParseError::InvalidTime)?;
+    let offset = match (offset_part, default_offset) {
+        (Some(part), _) => parse_rfc3339_offset(part).map_err(|_| ParseError::InvalidOffset)?,
+        (None, Some(offset)) => offset,
+        (None, None) => return Err(ParseError::InvalidOffset),
+    };
+    OffsetDateTime::from_local(date, time, offset).map_err(|_| ParseError::OutOfRange)
+}
+
+/// Reject non-ASCII text up front, so the byte offsets the parsers slice at
+/// are always character boundaries.
+#[inline]
+fn ensure_ascii(s: &str) -> Result<(), ParseError> {
+    if s.is_ascii() {
+        Ok(())
+    } else {
+        Err(ParseError::NonAscii)
+    }
+}
+
+/// Week and weekday digits of a "YYYY-Www-D" week date.
+fn parse_iso_week_date(year: i32, week: &[u8], weekday: &[u8]) -> Result<Date, DateError> {
+    if week.len() != 2 || weekday.len() != 1 {
+        return Err(DateError::InvalidDate);
+    }
+    let week = parse_u32_bytes(week, 53).ok_or(DateError::InvalidDate)? as u8;
+    let weekday = match weekday[0] {
+        b'1' => Weekday::Monday,
+        b'2' => Weekday::Tuesday,
+        b'3' => Weekday::Wednesday,
+        b'4' => Weekday::Thursday,
+        b'5' => Weekday::Friday,
+        b'6' => Weekday::Saturday,
+        b'7' => Weekday::Sunday,
+        _ => return Err(DateError::InvalidDate),
+    };
+    Date::from_iso_week(year as i64, week, weekday)
+}
+
+/// Rata Die number of 1970-01-01.
+const UNIX_EPOCH_RATA_DIE: i64 = 719_163;
+
+const fn is_leap_year(year: i32) -> bool {
     let century_candidate = year % 25 == 0;
     (year & if century_candidate { 15 } else { 3 }) == 0
 }
 
-fn days_in_month(year: i32, month: u8) -> u8 {
+const fn days_in_month(year: i32, month: u8) -> u8 {
     if month == 2 {
         return if is_leap_year(year) { 29 } else { 28 };
     }
-    if !(1..=12).contains(&month) {
+    if month < 1 || month > 12 {
         return 0;
     }
     // Branch-free month length for all non-February months.
     (month ^ (month >> 3)) | 30
 }
 
+/// Date for a month index (`year * 12 + month - 1`), with `day` clamped to
+/// the month length; `None` if the year is out of range.
+fn date_from_month_index(index: i64, day: u8) -> Option<Date> {
+    let year = i32::try_from(index.div_euclid(12)).ok()?;
+    let month = index.rem_euclid(12) as u8 + 1;
+    let day = day.clamp(1, days_in_month(year, month));
+    Some(Date { year, month, day })
+}
+
+/// First day of the month after `date`, or `None` past `Date::MAX`.
+fn first_of_next_month(date: Date) -> Option<Date> {
+    if date.month == 12 {
+        let year = date.year.checked_add(1)?;
+        Some(Date::from_ymd_unchecked(year, 1, 1))
+    } else {
+        Some(Date::from_ymd_unchecked(date.year, date.month + 1, 1))
+    }
+}
+
 // Modified Neri-Schneider inverse (civil → days), as documented by Ben Joffe.
 // Returns days since Unix epoch for a given Gregorian date.
 #[inline]
-fn days_from_civil(y: i32, m: u8, d: u8) -> i64 {
+const fn days_from_civil(y: i32, m: u8, d: u8) -> i64 {
     // Large enough so shifted years are non-negative for the full i32 range.
     const S: i64 = 5_368_710;
     const YEAR_SHIFT: i64 = 400 * S;
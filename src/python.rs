@@ -2,11 +2,17 @@
 
 #![cfg(feature = "python")]
 
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyOverflowError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyType;
+use pyo3::types::{
+    PyDateAccess, PyDateTime as PyStdDateTime, PyDelta, PyDeltaAccess, PyTimeAccess, PyType,
+    PyTzInfo, PyTzInfoAccess,
+};
 
-use crate::{Date, DateTime, Duration, OffsetDateTime, Time, UtcOffset, Weekday as RustWeekday};
+use crate::{
+    Date, DateError, DateTime, Duration, DurationError, Month as RustMonth, OffsetDateTime,
+    ParseError, Time, UtcOffset, Weekday as RustWeekday,
+};
 
 // ===== Weekday =====
 
@@ -37,13 +43,104 @@ impl PyWeekday {
         self.0.number_from_monday()
     }
 
+    /// Get the English name, e.g. "Monday".
+    #[pyo3(name = "long_name")]
+    fn long_name(&self) -> &'static str {
+        self.0.long_name()
+    }
+
+    /// Get the three-letter English abbreviation, e.g. "Mon".
+    #[pyo3(name = "short_name")]
+    fn short_name(&self) -> &'static str {
+        self.0.short_name()
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
 
     fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+// ===== Month =====
+
+#[pyclass(
+    name = "Month",
+    module = "fasttime",
+    eq,
+    hash,
+    frozen,
+    skip_from_py_object
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PyMonth(RustMonth);
+
+#[pymethods]
+impl PyMonth {
+    #[classattr]
+    const JANUARY: PyMonth = PyMonth(RustMonth::January);
+    #[classattr]
+    const FEBRUARY: PyMonth = PyMonth(RustMonth::February);
+    #[classattr]
+    const MARCH: PyMonth = PyMonth(RustMonth::March);
+    #[classattr]
+    const APRIL: PyMonth = PyMonth(RustMonth::April);
+    #[classattr]
+    const MAY: PyMonth = PyMonth(RustMonth::May);
+    #[classattr]
+    const JUNE: PyMonth = PyMonth(RustMonth::June);
+    #[classattr]
+    const JULY: PyMonth = PyMonth(RustMonth::July);
+    #[classattr]
+    const AUGUST: PyMonth = PyMonth(RustMonth::August);
+    #[classattr]
+    const SEPTEMBER: PyMonth = PyMonth(RustMonth::September);
+    #[classattr]
+    const OCTOBER: PyMonth = PyMonth(RustMonth::October);
+    #[classattr]
+    const NOVEMBER: PyMonth = PyMonth(RustMonth::November);
+    #[classattr]
+    const DECEMBER: PyMonth = PyMonth(RustMonth::December);
+
+    /// Create a Month from its number (1-12).
+    ///
+    /// Raises:
+    ///     ValueError: If the number is not in 1-12.
+    #[classmethod]
+    #[pyo3(name = "from_number")]
+    fn from_number(_cls: &Bound<'_, PyType>, number: u8) -> PyResult<Self> {
+        RustMonth::from_number(number)
+            .map(PyMonth)
+            .ok_or_else(|| PyValueError::new_err(format!("Invalid month: {}", number)))
+    }
+
+    /// Get the month number (1-12).
+    #[pyo3(name = "number")]
+    fn number(&self) -> u8 {
+        self.0.number()
+    }
+
+    /// Get the English name, e.g. "January".
+    #[pyo3(name = "long_name")]
+    fn long_name(&self) -> &'static str {
+        self.0.long_name()
+    }
+
+    /// Get the three-letter English abbreviation, e.g. "Jan".
+    #[pyo3(name = "short_name")]
+    fn short_name(&self) -> &'static str {
+        self.0.short_name()
+    }
+
+    fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
 }
 
 // ===== PyDate =====
@@ -118,12 +215,42 @@ impl PyDate {
         PyWeekday(self.0.weekday())
     }
 
+    /// Get days since the preceding Monday (0-6, Monday = 0).
+    #[pyo3(name = "num_days_from_monday")]
+    fn num_days_from_monday(&self) -> u8 {
+        self.0.num_days_from_monday()
+    }
+
+    /// Get days since the preceding Sunday (0-6, Sunday = 0).
+    #[pyo3(name = "num_days_from_sunday")]
+    fn num_days_from_sunday(&self) -> u8 {
+        self.0.num_days_from_sunday()
+    }
+
     /// Get the day of the year (1-366).
     #[pyo3(name = "ordinal")]
     fn ordinal(&self) -> u16 {
         self.0.ordinal()
     }
 
+    /// Same year with the month and day of a day-of-year.
+    ///
+    /// Args:
+    ///     ordinal: The day of the year (1-365, or 1-366 in leap years).
+    ///
+    /// Returns:
+    ///     Date: A new Date instance.
+    ///
+    /// Raises:
+    ///     ValueError: If the ordinal is outside the year.
+    #[pyo3(name = "with_ordinal")]
+    fn with_ordinal(&self, ordinal: u16) -> PyResult<Self> {
+        self.0
+            .with_ordinal(ordinal)
+            .map(PyDate)
+            .map_err(|e| PyValueError::new_err(format!("Invalid ordinal: {:?}", e)))
+    }
+
     /// Add days to the date.
     ///
     /// Args:
@@ -142,6 +269,82 @@ impl PyDate {
             .map_err(|e| PyValueError::new_err(format!("Date out of range: {:?}", e)))
     }
 
+    /// Subtract days from the date.
+    ///
+    /// Args:
+    ///     days: Number of days to subtract (can be negative).
+    ///
+    /// Returns:
+    ///     Date: A new Date instance.
+    ///
+    /// Raises:
+    ///     ValueError: If the resulting date is out of range.
+    #[pyo3(name = "sub_days")]
+    fn sub_days(&self, days: i64) -> PyResult<Self> {
+        self.0
+            .sub_days(days)
+            .map(PyDate)
+            .map_err(|e| PyValueError::new_err(format!("Date out of range: {:?}", e)))
+    }
+
+    /// Completed years from this date to `later`, as for an age.
+    ///
+    /// A February 29th date has its anniversary on February 28th in common
+    /// years. The result is negative if `later` is earlier than this date.
+    ///
+    /// Args:
+    ///     later: The end date.
+    ///
+    /// Returns:
+    ///     int: The number of completed years.
+    #[pyo3(name = "years_between")]
+    fn years_between(&self, later: &PyDate) -> i32 {
+        self.0.years_between(later.0)
+    }
+
+    /// Restrict this Date to the inclusive range `min..=max`.
+    ///
+    /// Args:
+    ///     min: The earliest allowed Date.
+    ///     max: The latest allowed Date.
+    ///
+    /// Returns:
+    ///     Date: `min` if this Date is earlier, `max` if it is later, else itself.
+    ///
+    /// Raises:
+    ///     ValueError: If `min` is after `max`.
+    #[pyo3(name = "clamp")]
+    fn clamp(&self, min: &PyDate, max: &PyDate) -> PyResult<Self> {
+        if min.0 > max.0 {
+            return Err(PyValueError::new_err("clamp: min is after max"));
+        }
+        Ok(PyDate(self.0.clamp(min.0, max.0)))
+    }
+
+    /// Get the next calendar day.
+    ///
+    /// Raises:
+    ///     ValueError: If the resulting date is out of range.
+    #[pyo3(name = "succ")]
+    fn succ(&self) -> PyResult<Self> {
+        self.0
+            .succ()
+            .map(PyDate)
+            .map_err(|e| PyValueError::new_err(format!("Date out of range: {:?}", e)))
+    }
+
+    /// Get the previous calendar day.
+    ///
+    /// Raises:
+    ///     ValueError: If the resulting date is out of range.
+    #[pyo3(name = "pred")]
+    fn pred(&self) -> PyResult<Self> {
+        self.0
+            .pred()
+            .map(PyDate)
+            .map_err(|e| PyValueError::new_err(format!("Date out of range: {:?}", e)))
+    }
+
     /// Parse a date from ISO format (YYYY-MM-DD).
     ///
     /// Args:
@@ -164,6 +367,22 @@ impl PyDate {
         self.0.to_string()
     }
 
+    /// Format with a strftime-like spec, as used by f-strings.
+    ///
+    /// Args:
+    ///     spec: Pattern using %Y, %y, %m, %d, %j, %u, %F and %%; an empty spec gives `str(self)`
+    ///
+    /// Raises:
+    ///     ValueError: If the spec uses an unsupported specifier
+    fn __format__(&self, spec: &str) -> PyResult<String> {
+        if spec.is_empty() {
+            return Ok(self.0.to_string());
+        }
+        self.0
+            .format(spec)
+            .map_err(|e| PyValueError::new_err(format!("Invalid format spec: {:?}", e)))
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "Date(year={}, month={}, day={})",
@@ -171,6 +390,11 @@ impl PyDate {
         )
     }
 
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (i32, u8, u8)) {
+        let d = slf.borrow().0;
+        (slf.get_type(), (d.year, d.month, d.day))
+    }
+
     fn __richcmp__(&self, other: &Self, op: pyo3::basic::CompareOp) -> PyResult<bool> {
         use pyo3::basic::CompareOp;
         match op {
@@ -256,6 +480,81 @@ impl PyTime {
         self.0.nanos_since_midnight()
     }
 
+    /// Signed difference between two times on the same day.
+    ///
+    /// Args:
+    ///     other: Another Time instance.
+    ///
+    /// Returns:
+    ///     Duration: self - other, negative if `other` is later.
+    #[pyo3(name = "difference")]
+    fn difference(&self, other: &PyTime) -> PyDuration {
+        PyDuration(self.0.difference(other.0))
+    }
+
+    /// Shortest distance between two clock times, wrapping around midnight.
+    ///
+    /// Args:
+    ///     other: Another Time instance.
+    ///
+    /// Returns:
+    ///     Duration: A non-negative duration of at most 12 hours.
+    #[pyo3(name = "abs_difference")]
+    fn abs_difference(&self, other: &PyTime) -> PyDuration {
+        PyDuration(self.0.abs_difference(other.0))
+    }
+
+    /// Round to the nearest second, half up.
+    ///
+    /// Returns:
+    ///     tuple[Time, bool]: The rounded time and whether it rolled over
+    ///     into the next day.
+    #[pyo3(name = "round_to_second")]
+    fn round_to_second(&self) -> (PyTime, bool) {
+        let (time, wrapped) = self.0.round_to_second();
+        (PyTime(time), wrapped)
+    }
+
+    /// Round to the nearest millisecond, half up.
+    ///
+    /// Returns:
+    ///     tuple[Time, bool]: The rounded time and whether it rolled over
+    ///     into the next day.
+    #[pyo3(name = "round_to_millis")]
+    fn round_to_millis(&self) -> (PyTime, bool) {
+        let (time, wrapped) = self.0.round_to_millis();
+        (PyTime(time), wrapped)
+    }
+
+    /// Round to the nearest microsecond, half up.
+    ///
+    /// Returns:
+    ///     tuple[Time, bool]: The rounded time and whether it rolled over
+    ///     into the next day.
+    #[pyo3(name = "round_to_micros")]
+    fn round_to_micros(&self) -> (PyTime, bool) {
+        let (time, wrapped) = self.0.round_to_micros();
+        (PyTime(time), wrapped)
+    }
+
+    /// Create a Time from nanoseconds since midnight.
+    ///
+    /// Args:
+    ///     nanos: Nanoseconds since midnight (0 to 86,399,999,999,999).
+    ///
+    /// Returns:
+    ///     Time: A new Time instance.
+    ///
+    /// Raises:
+    ///     ValueError: If nanos is a full day or more.
+    #[classmethod]
+    #[pyo3(name = "from_nanos_since_midnight")]
+    fn from_nanos_since_midnight(_cls: &Bound<'_, PyType>, nanos: u64) -> PyResult<Self> {
+        Time::from_nanos_since_midnight(nanos)
+            .map(PyTime)
+            .map_err(|e| PyValueError::new_err(format!("Invalid time: {:?}", e)))
+    }
+
     /// Parse a time from ISO format (HH:MM:SS[.fffffffff]).
     ///
     /// Args:
@@ -278,6 +577,22 @@ impl PyTime {
         self.0.to_string()
     }
 
+    /// Format with a strftime-like spec, as used by f-strings.
+    ///
+    /// Args:
+    ///     spec: Pattern using %H, %M, %S, %f, %T and %%; an empty spec gives `str(self)`
+    ///
+    /// Raises:
+    ///     ValueError: If the spec uses an unsupported specifier
+    fn __format__(&self, spec: &str) -> PyResult<String> {
+        if spec.is_empty() {
+            return Ok(self.0.to_string());
+        }
+        self.0
+            .format(spec)
+            .map_err(|e| PyValueError::new_err(format!("Invalid format spec: {:?}", e)))
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "Time(hour={}, minute={}, second={}, nanosecond={})",
@@ -285,6 +600,11 @@ impl PyTime {
         )
     }
 
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (u8, u8, u8, u32)) {
+        let t = slf.borrow().0;
+        (slf.get_type(), (t.hour, t.minute, t.second, t.nanosecond))
+    }
+
     fn __richcmp__(&self, other: &Self, op: pyo3::basic::CompareOp) -> PyResult<bool> {
         use pyo3::basic::CompareOp;
         match op {
@@ -312,6 +632,13 @@ impl PyTime {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PyDuration(Duration);
 
+fn duration_error_to_py(e: DurationError) -> PyErr {
+    match e {
+        DurationError::NotFinite => PyValueError::new_err("Duration must be finite"),
+        DurationError::Overflow => PyOverflowError::new_err("Duration out of range"),
+    }
+}
+
 #[pymethods]
 impl PyDuration {
     /// Create a duration from seconds.
@@ -342,6 +669,34 @@ impl PyDuration {
         PyDuration(Duration::nanoseconds(ns))
     }
 
+    /// Create a duration from fractional seconds, rounded to the nearest
+    /// nanosecond.
+    ///
+    /// Raises:
+    ///     ValueError: If the value is NaN or infinite.
+    ///     OverflowError: If the value is out of range.
+    #[classmethod]
+    #[pyo3(name = "from_seconds_f64")]
+    fn from_seconds_f64(_cls: &Bound<'_, PyType>, secs: f64) -> PyResult<Self> {
+        Duration::from_seconds_f64(secs)
+            .map(PyDuration)
+            .map_err(duration_error_to_py)
+    }
+
+    /// Create a duration from fractional milliseconds, rounded to the
+    /// nearest nanosecond.
+    ///
+    /// Raises:
+    ///     ValueError: If the value is NaN or infinite.
+    ///     OverflowError: If the value is out of range.
+    #[classmethod]
+    #[pyo3(name = "from_millis_f64")]
+    fn from_millis_f64(_cls: &Bound<'_, PyType>, ms: f64) -> PyResult<Self> {
+        Duration::from_millis_f64(ms)
+            .map(PyDuration)
+            .map_err(duration_error_to_py)
+    }
+
     /// Get total seconds as a float.
     #[pyo3(name = "total_seconds")]
     fn total_seconds(&self) -> f64 {
@@ -354,6 +709,48 @@ impl PyDuration {
         self.0.total_nanos()
     }
 
+    /// Split into components with the sign factored out.
+    ///
+    /// Returns:
+    ///     tuple: (negative, days, hours, minutes, seconds, nanoseconds).
+    #[pyo3(name = "to_parts")]
+    #[allow(
+        clippy::wrong_self_convention,
+        reason = "pyo3 methods cannot take `self` by value"
+    )]
+    fn to_parts(&self) -> (bool, u128, u8, u8, u8, u32) {
+        let p = self.0.to_parts();
+        (
+            p.negative,
+            p.days,
+            p.hours,
+            p.minutes,
+            p.seconds,
+            p.nanoseconds,
+        )
+    }
+
+    /// Format as a stopwatch-style "HH:MM:SS" string, e.g. "01:30:15".
+    ///
+    /// Hours are not wrapped into days, and negative durations get a
+    /// leading "-". Sub-second parts are truncated.
+    ///
+    /// Args:
+    ///     digits: Number of fractional-second digits (0-9, default 0).
+    ///
+    /// Returns:
+    ///     str: The formatted duration.
+    #[pyo3(name = "to_clock_string", signature = (digits=0))]
+    #[allow(
+        clippy::wrong_self_convention,
+        reason = "pyo3 methods cannot take `self` by value"
+    )]
+    fn to_clock_string(&self, digits: u8) -> String {
+        let mut out = String::new();
+        let _ = self.0.format_clock_into(&mut out, digits);
+        out
+    }
+
     fn __add__(&self, other: &Self) -> Self {
         PyDuration(self.0 + other.0)
     }
@@ -367,13 +764,18 @@ impl PyDuration {
     }
 
     fn __str__(&self) -> String {
-        format!("Duration({} ns)", self.0.total_nanos())
+        self.0.to_string()
     }
 
     fn __repr__(&self) -> String {
         format!("Duration.nanoseconds({})", self.0.total_nanos())
     }
 
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (i128,))> {
+        let nanos = slf.borrow().0.total_nanos();
+        Ok((slf.get_type().getattr("nanoseconds")?, (nanos,)))
+    }
+
     fn __richcmp__(&self, other: &Self, op: pyo3::basic::CompareOp) -> PyResult<bool> {
         use pyo3::basic::CompareOp;
         match op {
@@ -426,6 +828,80 @@ impl PyDateTime {
         PyTime(self.0.time)
     }
 
+    #[getter]
+    fn year(&self) -> i32 {
+        self.0.year()
+    }
+
+    #[getter]
+    fn month(&self) -> u8 {
+        self.0.month()
+    }
+
+    #[getter]
+    fn day(&self) -> u8 {
+        self.0.day()
+    }
+
+    #[getter]
+    fn hour(&self) -> u8 {
+        self.0.hour()
+    }
+
+    #[getter]
+    fn minute(&self) -> u8 {
+        self.0.minute()
+    }
+
+    #[getter]
+    fn second(&self) -> u8 {
+        self.0.second()
+    }
+
+    #[getter]
+    fn nanosecond(&self) -> u32 {
+        self.0.nanosecond()
+    }
+
+    /// Get the weekday.
+    #[pyo3(name = "weekday")]
+    fn weekday(&self) -> PyWeekday {
+        PyWeekday(self.0.weekday())
+    }
+
+    /// Get the day of the year (1-366).
+    #[pyo3(name = "ordinal")]
+    fn ordinal(&self) -> u16 {
+        self.0.ordinal()
+    }
+
+    /// Return a copy with the date replaced, keeping the time of day.
+    #[pyo3(name = "with_date")]
+    fn with_date(&self, date: &PyDate) -> Self {
+        PyDateTime(self.0.with_date(date.0))
+    }
+
+    /// Return a copy with the time of day replaced, keeping the date.
+    #[pyo3(name = "with_time")]
+    fn with_time(&self, time: &PyTime) -> Self {
+        PyDateTime(self.0.with_time(time.0))
+    }
+
+    /// Return a copy with the nanosecond replaced.
+    ///
+    /// Args:
+    ///     nanosecond: The new nanosecond (0-999,999,999).
+    ///
+    /// Raises:
+    ///     ValueError: If the nanosecond is out of range.
+    #[pyo3(name = "with_nanosecond")]
+    fn with_nanosecond(&self, nanosecond: u32) -> PyResult<Self> {
+        self.0
+            .with_nanosecond(nanosecond)
+            .map(PyDateTime)
+            .map_err(|e| PyValueError::new_err(format!("Invalid time: {:?}", e)))
+    }
+
     /// Create a DateTime from Unix timestamp (seconds and nanoseconds).
     ///
     /// Args:
@@ -457,6 +933,91 @@ impl PyDateTime {
         self.0.unix_timestamp_nanos()
     }
 
+    /// Get Unix timestamp in milliseconds (rounded down).
+    ///
+    /// Raises:
+    ///     OverflowError: If the value doesn't fit in a signed 64-bit integer.
+    #[pyo3(name = "unix_timestamp_millis")]
+    fn unix_timestamp_millis(&self) -> PyResult<i64> {
+        self.0
+            .unix_timestamp_millis()
+            .map_err(|e| PyOverflowError::new_err(format!("Timestamp out of range: {:?}", e)))
+    }
+
+    /// Get Unix timestamp in microseconds (rounded down).
+    ///
+    /// Raises:
+    ///     OverflowError: If the value doesn't fit in a signed 64-bit integer.
+    #[pyo3(name = "unix_timestamp_micros")]
+    fn unix_timestamp_micros(&self) -> PyResult<i64> {
+        self.0
+            .unix_timestamp_micros()
+            .map_err(|e| PyOverflowError::new_err(format!("Timestamp out of range: {:?}", e)))
+    }
+
+    /// Create a DateTime from milliseconds since the Unix epoch.
+    ///
+    /// Args:
+    ///     ms: Milliseconds since Unix epoch.
+    ///
+    /// Returns:
+    ///     DateTime: A new DateTime instance.
+    ///
+    /// Raises:
+    ///     ValueError: If the timestamp is invalid.
+    #[classmethod]
+    #[pyo3(name = "from_unix_timestamp_millis")]
+    fn from_unix_timestamp_millis(_cls: &Bound<'_, PyType>, ms: i64) -> PyResult<Self> {
+        DateTime::from_unix_timestamp_millis(ms)
+            .map(PyDateTime)
+            .map_err(|e| PyValueError::new_err(format!("Invalid timestamp: {:?}", e)))
+    }
+
+    /// Create a DateTime from microseconds since the Unix epoch.
+    ///
+    /// Args:
+    ///     us: Microseconds since Unix epoch.
+    ///
+    /// Returns:
+    ///     DateTime: A new DateTime instance.
+    ///
+    /// Raises:
+    ///     ValueError: If the timestamp is invalid.
+    #[classmethod]
+    #[pyo3(name = "from_unix_timestamp_micros")]
+    fn from_unix_timestamp_micros(_cls: &Bound<'_, PyType>, us: i64) -> PyResult<Self> {
+        DateTime::from_unix_timestamp_micros(us)
+            .map(PyDateTime)
+            .map_err(|e| PyValueError::new_err(format!("Invalid timestamp: {:?}", e)))
+    }
+
+    /// Create a DateTime from fractional seconds since the Unix epoch.
+    ///
+    /// The fraction is rounded to the nearest nanosecond; precision is
+    /// limited by the float (about a microsecond for present-day values).
+    ///
+    /// Args:
+    ///     secs: Seconds since Unix epoch, as a float.
+    ///
+    /// Returns:
+    ///     DateTime: A new DateTime instance.
+    ///
+    /// Raises:
+    ///     ValueError: If the value is NaN, infinite or out of range.
+    #[classmethod]
+    #[pyo3(name = "from_unix_timestamp_f64")]
+    fn from_unix_timestamp_f64(_cls: &Bound<'_, PyType>, secs: f64) -> PyResult<Self> {
+        DateTime::from_unix_timestamp_f64(secs)
+            .map(PyDateTime)
+            .map_err(|e| PyValueError::new_err(format!("Invalid timestamp: {:?}", e)))
+    }
+
+    /// Get Unix timestamp as fractional seconds (lossy).
+    #[pyo3(name = "unix_timestamp_f64")]
+    fn unix_timestamp_f64(&self) -> f64 {
+        self.0.unix_timestamp_f64()
+    }
+
     /// Add a duration to this DateTime.
     ///
     /// Args:
@@ -487,6 +1048,117 @@ impl PyDateTime {
         PyDuration(self.0.difference(other.0))
     }
 
+    /// Restrict this DateTime to the inclusive range `min..=max`.
+    ///
+    /// Args:
+    ///     min: The earliest allowed DateTime.
+    ///     max: The latest allowed DateTime.
+    ///
+    /// Returns:
+    ///     DateTime: `min` if this DateTime is earlier, `max` if it is later, else itself.
+    ///
+    /// Raises:
+    ///     ValueError: If `min` is after `max`.
+    #[pyo3(name = "clamp")]
+    fn clamp(&self, min: &PyDateTime, max: &PyDateTime) -> PyResult<Self> {
+        if min.0 > max.0 {
+            return Err(PyValueError::new_err("clamp: min is after max"));
+        }
+        Ok(PyDateTime(self.0.clamp(min.0, max.0)))
+    }
+
+    /// Attach a fixed offset, treating this DateTime as the UTC instant.
+    ///
+    /// Args:
+    ///     offset: A UtcOffset instance.
+    ///
+    /// Returns:
+    ///     OffsetDateTime: The same instant viewed at the given offset.
+    #[pyo3(name = "assume_offset")]
+    fn assume_offset(&self, offset: &PyUtcOffset) -> PyOffsetDateTime {
+        PyOffsetDateTime(self.0.assume_offset(offset.0))
+    }
+
+    /// Attach the UTC offset (+00:00).
+    #[pyo3(name = "assume_utc")]
+    fn assume_utc(&self) -> PyOffsetDateTime {
+        PyOffsetDateTime(self.0.assume_utc())
+    }
+
+    /// Convert to a timezone-aware `datetime.datetime` in UTC.
+    ///
+    /// Python datetimes only have microsecond precision, so the sub-microsecond
+    /// part of the nanosecond field is truncated.
+    ///
+    /// Raises:
+    ///     ValueError: If the year is outside Python's supported range (1-9999).
+    #[pyo3(name = "to_pydatetime")]
+    #[allow(
+        clippy::wrong_self_convention,
+        reason = "pyo3 methods cannot take `self` by value"
+    )]
+    fn to_pydatetime<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyStdDateTime>> {
+        let utc = PyTzInfo::utc(py)?;
+        let (date, time) = (self.0.date, self.0.time);
+        PyStdDateTime::new(
+            py,
+            date.year,
+            date.month,
+            date.day,
+            time.hour,
+            time.minute,
+            time.second,
+            time.nanosecond / 1_000,
+            Some(&utc),
+        )
+    }
+
+    /// Create a DateTime from a `datetime.datetime`.
+    ///
+    /// Aware datetimes are converted to UTC using `tzinfo.utcoffset()`; naive
+    /// datetimes are taken to already be in UTC. Microseconds are extended to
+    /// nanoseconds with zeros.
+    ///
+    /// Args:
+    ///     dt: A `datetime.datetime` instance.
+    ///
+    /// Returns:
+    ///     DateTime: A new DateTime instance.
+    ///
+    /// Raises:
+    ///     ValueError: If the result is out of range.
+    #[classmethod]
+    #[pyo3(name = "from_pydatetime")]
+    fn from_pydatetime(_cls: &Bound<'_, PyType>, dt: &Bound<'_, PyStdDateTime>) -> PyResult<Self> {
+        let date = Date::from_ymd(dt.get_year(), dt.get_month(), dt.get_day())
+            .map_err(|e| PyValueError::new_err(format!("Invalid date: {:?}", e)))?;
+        let time = Time::from_hms_nano(
+            dt.get_hour(),
+            dt.get_minute(),
+            dt.get_second(),
+            dt.get_microsecond() * 1_000,
+        )
+        .map_err(|e| PyValueError::new_err(format!("Invalid time: {:?}", e)))?;
+        let local = DateTime::new(date, time);
+
+        let offset = match dt.get_tzinfo() {
+            Some(_) => dt
+                .call_method0("utcoffset")?
+                .extract::<Option<Bound<'_, PyDelta>>>()?,
+            None => None,
+        };
+        let Some(offset) = offset else {
+            return Ok(PyDateTime(local));
+        };
+        let offset_micros = (offset.get_days() as i64 * 86_400 + offset.get_seconds() as i64)
+            * 1_000_000
+            + offset.get_microseconds() as i64;
+        local
+            .add_duration(-Duration::microseconds(offset_micros))
+            .map(PyDateTime)
+            .map_err(|e| PyValueError::new_err(format!("DateTime out of range: {:?}", e)))
+    }
+
     /// Get the current UTC DateTime (requires std feature).
     #[classmethod]
     #[pyo3(name = "now_utc")]
@@ -505,6 +1177,30 @@ impl PyDateTime {
         }
     }
 
+    /// Time from this DateTime until now (requires std feature).
+    ///
+    /// Returns:
+    ///     Duration: Positive for past instants, negative for future ones.
+    ///
+    /// Raises:
+    ///     ValueError: If the current time cannot be read.
+    #[pyo3(name = "elapsed")]
+    fn elapsed(&self) -> PyResult<PyDuration> {
+        #[cfg(feature = "std")]
+        {
+            self.0
+                .elapsed()
+                .map(PyDuration)
+                .map_err(|e| PyValueError::new_err(format!("Failed to get current time: {:?}", e)))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Err(PyValueError::new_err(
+                "elapsed() requires the 'std' feature",
+            ))
+        }
+    }
+
     /// Parse a DateTime from ISO 8601 / RFC 3339 UTC format.
     ///
     /// Args:
@@ -518,22 +1214,79 @@ impl PyDateTime {
     #[classmethod]
     #[pyo3(name = "parse")]
     fn parse(_cls: &Bound<'_, PyType>, s: &str) -> PyResult<Self> {
-        s.parse::<DateTime>().map(PyDateTime).map_err(|_| {
-            PyValueError::new_err(format!(
+        s.parse::<DateTime>().map(PyDateTime).map_err(|e| match e {
+            ParseError::UseOffsetDateTime => PyValueError::new_err(format!(
+                "Invalid datetime string '{}': it has a UTC offset, use OffsetDateTime.parse",
+                s
+            )),
+            _ => PyValueError::new_err(format!(
                 "Invalid datetime string '{}'. Expected format: YYYY-MM-DDTHH:MM:SS[.fffffffff]Z",
                 s
-            ))
+            )),
         })
     }
 
+    fn __add__(&self, dur: &PyDuration) -> PyResult<Self> {
+        self.0
+            .add_duration(dur.0)
+            .map(PyDateTime)
+            .map_err(|e| PyOverflowError::new_err(format!("DateTime out of range: {:?}", e)))
+    }
+
+    fn __radd__(&self, dur: &PyDuration) -> PyResult<Self> {
+        self.__add__(dur)
+    }
+
+    /// `dt - duration` yields a DateTime; `dt - other_dt` yields a Duration.
+    fn __sub__(&self, py: Python<'_>, other: DateTimeSubOperand<'_>) -> PyResult<Py<PyAny>> {
+        match other {
+            DateTimeSubOperand::Duration(dur) => {
+                let dt = dur
+                    .0
+                    .checked_neg()
+                    .ok_or(DateError::OutOfRange)
+                    .and_then(|neg| self.0.add_duration(neg))
+                    .map(PyDateTime)
+                    .map_err(|e| {
+                        PyOverflowError::new_err(format!("DateTime out of range: {:?}", e))
+                    })?;
+                Ok(Py::new(py, dt)?.into_any())
+            }
+            DateTimeSubOperand::DateTime(other) => {
+                Ok(Py::new(py, PyDuration(self.0.difference(other.0)))?.into_any())
+            }
+        }
+    }
+
     fn __str__(&self) -> String {
         self.0.to_string()
     }
 
+    /// Format with a strftime-like spec, as used by f-strings.
+    ///
+    /// Args:
+    ///     spec: Pattern using any of the Date and Time specifiers; an empty spec gives `str(self)`
+    ///
+    /// Raises:
+    ///     ValueError: If the spec uses an unsupported specifier
+    fn __format__(&self, spec: &str) -> PyResult<String> {
+        if spec.is_empty() {
+            return Ok(self.0.to_string());
+        }
+        self.0
+            .format(spec)
+            .map_err(|e| PyValueError::new_err(format!("Invalid format spec: {:?}", e)))
+    }
+
     fn __repr__(&self) -> String {
         format!("DateTime.parse('{}')", self.0)
     }
 
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (PyDate, PyTime)) {
+        let dt = slf.borrow().0;
+        (slf.get_type(), (PyDate(dt.date), PyTime(dt.time)))
+    }
+
     fn __richcmp__(&self, other: &Self, op: pyo3::basic::CompareOp) -> PyResult<bool> {
         use pyo3::basic::CompareOp;
         match op {
@@ -563,19 +1316,34 @@ pub struct PyUtcOffset(UtcOffset);
 
 #[pymethods]
 impl PyUtcOffset {
+    #[classattr]
+    const UTC: PyUtcOffset = PyUtcOffset(UtcOffset::UTC);
+
     /// Create a UtcOffset from total seconds.
     ///
     /// Args:
     ///     seconds: Total seconds offset from UTC (roughly -86400 to +86400).
+    ///     unknown_local: Mark a zero offset as `-00:00`, "local offset
+    ///         unknown" (default=False).
     ///
     /// Returns:
     ///     UtcOffset: A new UtcOffset instance.
     ///
     /// Raises:
-    ///     ValueError: If the offset is out of range.
+    ///     ValueError: If the offset is out of range, or `unknown_local` is
+    ///         set for a non-zero offset.
     #[classmethod]
-    #[pyo3(name = "from_seconds")]
-    fn from_seconds(_cls: &Bound<'_, PyType>, seconds: i32) -> PyResult<Self> {
+    #[pyo3(name = "from_seconds", signature = (seconds, unknown_local=false))]
+    fn from_seconds(_cls: &Bound<'_, PyType>, seconds: i32, unknown_local: bool) -> PyResult<Self> {
+        if unknown_local {
+            return if seconds == 0 {
+                Ok(PyUtcOffset(UtcOffset::UNKNOWN_LOCAL))
+            } else {
+                Err(PyValueError::new_err(
+                    "unknown_local requires a zero offset",
+                ))
+            };
+        }
         UtcOffset::from_seconds(seconds)
             .map(PyUtcOffset)
             .map_err(|e| PyValueError::new_err(format!("Invalid offset: {:?}", e)))
@@ -606,6 +1374,24 @@ impl PyUtcOffset {
             .map_err(|e| PyValueError::new_err(format!("Invalid offset: {:?}", e)))
     }
 
+    /// Create a UtcOffset from a signed whole number of hours.
+    ///
+    /// Args:
+    ///     hours: Hours from UTC (-12 to 14).
+    ///
+    /// Returns:
+    ///     UtcOffset: A new UtcOffset instance.
+    ///
+    /// Raises:
+    ///     ValueError: If hours is out of range.
+    #[classmethod]
+    #[pyo3(name = "from_whole_hours")]
+    fn from_whole_hours(_cls: &Bound<'_, PyType>, hours: i8) -> PyResult<Self> {
+        UtcOffset::from_whole_hours(hours)
+            .map(PyUtcOffset)
+            .map_err(|e| PyValueError::new_err(format!("Invalid offset: {:?}", e)))
+    }
+
     /// Get the offset as seconds.
     #[pyo3(name = "as_seconds")]
     fn as_seconds(&self) -> i32 {
@@ -618,12 +1404,55 @@ impl PyUtcOffset {
         self.0.is_utc()
     }
 
+    /// Check if this is the "-00:00" unknown-local-offset marker.
+    #[pyo3(name = "is_unknown_local")]
+    fn is_unknown_local(&self) -> bool {
+        self.0.is_unknown_local()
+    }
+
+    /// Return the offset with its sign flipped (+05:30 becomes -05:30).
+    #[pyo3(name = "negate")]
+    fn negate(&self) -> Self {
+        PyUtcOffset(-self.0)
+    }
+
+    /// Shift by `delta_seconds`, clamping to +/-24 hours.
+    ///
+    /// Args:
+    ///     delta_seconds: Seconds to add (can be negative).
+    ///
+    /// Returns:
+    ///     UtcOffset: The shifted offset.
+    #[pyo3(name = "saturating_add")]
+    fn saturating_add(&self, delta_seconds: i32) -> Self {
+        PyUtcOffset(self.0.saturating_add(delta_seconds))
+    }
+
+    fn __neg__(&self) -> Self {
+        self.negate()
+    }
+
     fn __str__(&self) -> String {
         self.0.to_string()
     }
 
     fn __repr__(&self) -> String {
-        format!("UtcOffset.from_seconds({})", self.0.as_seconds())
+        if self.0.is_unknown_local() {
+            format!(
+                "UtcOffset.from_seconds({}, unknown_local=True)",
+                self.0.as_seconds()
+            )
+        } else {
+            format!("UtcOffset.from_seconds({})", self.0.as_seconds())
+        }
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (i32, bool))> {
+        let offset = slf.borrow().0;
+        Ok((
+            slf.get_type().getattr("from_seconds")?,
+            (offset.as_seconds(), offset.is_unknown_local()),
+        ))
     }
 
     fn __richcmp__(&self, other: &Self, op: pyo3::basic::CompareOp) -> PyResult<bool> {
@@ -669,6 +1498,31 @@ impl PyOffsetDateTime {
         PyOffsetDateTime(OffsetDateTime::from_utc(utc.0, offset.0))
     }
 
+    /// Create an OffsetDateTime from a Unix timestamp, shown at `offset`.
+    ///
+    /// Args:
+    ///     secs: Seconds since Unix epoch.
+    ///     nanos: Additional nanoseconds.
+    ///     offset: A UtcOffset instance for the local view.
+    ///
+    /// Returns:
+    ///     OffsetDateTime: A new OffsetDateTime instance.
+    ///
+    /// Raises:
+    ///     ValueError: If the timestamp is invalid.
+    #[classmethod]
+    #[pyo3(name = "from_unix_timestamp")]
+    fn from_unix_timestamp(
+        _cls: &Bound<'_, PyType>,
+        secs: i64,
+        nanos: i32,
+        offset: &PyUtcOffset,
+    ) -> PyResult<Self> {
+        OffsetDateTime::from_unix_timestamp(secs, nanos, offset.0)
+            .map(PyOffsetDateTime)
+            .map_err(|e| PyValueError::new_err(format!("Invalid timestamp: {:?}", e)))
+    }
+
     /// Create an OffsetDateTime from local date, time, and offset.
     ///
     /// Args:
@@ -704,6 +1558,65 @@ impl PyOffsetDateTime {
         PyUtcOffset(self.0.offset)
     }
 
+    /// Local date as seen in this offset.
+    #[getter]
+    fn local_date(&self) -> PyResult<PyDate> {
+        self.0
+            .local_date()
+            .map(PyDate)
+            .map_err(|e| PyValueError::new_err(format!("Local datetime out of range: {:?}", e)))
+    }
+
+    /// Local time of day as seen in this offset.
+    #[getter]
+    fn local_time(&self) -> PyResult<PyTime> {
+        self.0
+            .local_time()
+            .map(PyTime)
+            .map_err(|e| PyValueError::new_err(format!("Local datetime out of range: {:?}", e)))
+    }
+
+    #[getter]
+    fn year(&self) -> PyResult<i32> {
+        Ok(self.local_date()?.0.year)
+    }
+
+    #[getter]
+    fn month(&self) -> PyResult<u8> {
+        Ok(self.local_date()?.0.month)
+    }
+
+    #[getter]
+    fn day(&self) -> PyResult<u8> {
+        Ok(self.local_date()?.0.day)
+    }
+
+    #[getter]
+    fn hour(&self) -> PyResult<u8> {
+        Ok(self.local_time()?.0.hour)
+    }
+
+    #[getter]
+    fn minute(&self) -> PyResult<u8> {
+        Ok(self.local_time()?.0.minute)
+    }
+
+    #[getter]
+    fn second(&self) -> PyResult<u8> {
+        Ok(self.local_time()?.0.second)
+    }
+
+    #[getter]
+    fn nanosecond(&self) -> u32 {
+        self.0.nanosecond()
+    }
+
+    /// Get the local weekday.
+    #[pyo3(name = "weekday")]
+    fn weekday(&self) -> PyResult<PyWeekday> {
+        Ok(PyWeekday(self.local_date()?.0.weekday()))
+    }
+
     /// Convert to local DateTime.
     ///
     /// Returns:
@@ -761,6 +1674,18 @@ impl PyOffsetDateTime {
         PyDuration(self.0.difference(other.0))
     }
 
+    /// Check whether both fall on the same local date, each in its own offset.
+    ///
+    /// Args:
+    ///     other: Another OffsetDateTime instance.
+    ///
+    /// Returns:
+    ///     bool: True if the local calendar dates are equal.
+    #[pyo3(name = "is_same_local_date")]
+    fn is_same_local_date(&self, other: &PyOffsetDateTime) -> bool {
+        self.0.is_same_local_date(&other.0)
+    }
+
     /// Parse an OffsetDateTime from RFC 3339 format.
     ///
     /// Args:
@@ -784,6 +1709,38 @@ impl PyOffsetDateTime {
             })
     }
 
+    fn __add__(&self, dur: &PyDuration) -> PyResult<Self> {
+        self.0
+            .add_duration(dur.0)
+            .map(PyOffsetDateTime)
+            .map_err(|e| PyOverflowError::new_err(format!("DateTime out of range: {:?}", e)))
+    }
+
+    fn __radd__(&self, dur: &PyDuration) -> PyResult<Self> {
+        self.__add__(dur)
+    }
+
+    /// `odt - duration` yields an OffsetDateTime; `odt - other_odt` yields a Duration.
+    fn __sub__(&self, py: Python<'_>, other: OffsetDateTimeSubOperand<'_>) -> PyResult<Py<PyAny>> {
+        match other {
+            OffsetDateTimeSubOperand::Duration(dur) => {
+                let odt = dur
+                    .0
+                    .checked_neg()
+                    .ok_or(DateError::OutOfRange)
+                    .and_then(|neg| self.0.add_duration(neg))
+                    .map(PyOffsetDateTime)
+                    .map_err(|e| {
+                        PyOverflowError::new_err(format!("DateTime out of range: {:?}", e))
+                    })?;
+                Ok(Py::new(py, odt)?.into_any())
+            }
+            OffsetDateTimeSubOperand::OffsetDateTime(other) => {
+                Ok(Py::new(py, PyDuration(self.0.difference(other.0)))?.into_any())
+            }
+        }
+    }
+
     fn __str__(&self) -> String {
         self.0.to_string()
     }
@@ -792,6 +1749,16 @@ impl PyOffsetDateTime {
         format!("OffsetDateTime.parse('{}')", self.0)
     }
 
+    fn __reduce__<'py>(
+        slf: &Bound<'py, Self>,
+    ) -> PyResult<(Bound<'py, PyAny>, (PyDateTime, PyUtcOffset))> {
+        let odt = slf.borrow().0;
+        Ok((
+            slf.get_type().getattr("from_utc")?,
+            (PyDateTime(odt.utc), PyUtcOffset(odt.offset)),
+        ))
+    }
+
     fn __richcmp__(&self, other: &Self, op: pyo3::basic::CompareOp) -> PyResult<bool> {
         use pyo3::basic::CompareOp;
         match op {
@@ -813,11 +1780,28 @@ impl PyOffsetDateTime {
     }
 }
 
+// ===== Operator operands =====
+
+/// Right-hand side accepted by `DateTime.__sub__`.
+#[derive(FromPyObject)]
+enum DateTimeSubOperand<'py> {
+    Duration(PyRef<'py, PyDuration>),
+    DateTime(PyRef<'py, PyDateTime>),
+}
+
+/// Right-hand side accepted by `OffsetDateTime.__sub__`.
+#[derive(FromPyObject)]
+enum OffsetDateTimeSubOperand<'py> {
+    Duration(PyRef<'py, PyDuration>),
+    OffsetDateTime(PyRef<'py, PyOffsetDateTime>),
+}
+
 // ===== Module definition =====
 
 #[pymodule]
 fn fasttime(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyWeekday>()?;
+    m.add_class::<PyMonth>()?;
     m.add_class::<PyDate>()?;
     m.add_class::<PyTime>()?;
     m.add_class::<PyDuration>()?;
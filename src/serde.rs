@@ -0,0 +1,235 @@
+//! serde support: `Serialize`/`Deserialize` for [`Date`], [`Time`] and
+//! [`DateTime`], plus helpers for use with `#[serde(with = "...")]`.
+//!
+//! The impls follow `is_human_readable()`. Text formats (JSON, YAML, TOML)
+//! get the same strings as `Display`/`FromStr`; binary formats (bincode,
+//! postcard, ...) get a compact numeric layout:
+//!
+//! | Type       | Human-readable             | Compact                                 |
+//! |------------|----------------------------|-----------------------------------------|
+//! | `Date`     | `"2023-11-05"`             | `i64` days since 1970-01-01             |
+//! | `Time`     | `"23:59:59.5"`             | `u64` nanoseconds since midnight        |
+//! | `DateTime` | `"2023-11-05T23:59:59.5Z"` | `(i64, u32)`: Unix seconds, nanoseconds |
+//!
+//! In the `DateTime` tuple the seconds are floored, so the nanoseconds are
+//! always in `0..1_000_000_000` (the same split as
+//! [`DateTime::unix_timestamp`] and the `nanosecond` field).
+
+#![cfg(feature = "serde")]
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use ::serde::de::{self, Visitor};
+use ::serde::ser::SerializeTuple;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Date, DateTime, Time};
+
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_i64(self.days_since_unix_epoch())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(FromStrVisitor::new("a date such as \"2023-11-05\""))
+        } else {
+            let days = i64::deserialize(deserializer)?;
+            Date::from_days_since_unix_epoch(days).map_err(|e| {
+                de::Error::custom(format_args!("day count {} out of range: {:?}", days, e))
+            })
+        }
+    }
+}
+
+impl Serialize for Time {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_u64(self.nanos_since_midnight())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Time {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Time, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(FromStrVisitor::new("a time such as \"23:59:59.5\""))
+        } else {
+            let nanos = u64::deserialize(deserializer)?;
+            Time::from_nanos_since_midnight(nanos).map_err(|e| {
+                de::Error::custom(format_args!(
+                    "{} nanoseconds is not a time of day: {:?}",
+                    nanos, e
+                ))
+            })
+        }
+    }
+}
+
+impl Serialize for DateTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&self.unix_timestamp())?;
+            tuple.serialize_element(&self.time.nanosecond)?;
+            tuple.end()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DateTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<DateTime, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(FromStrVisitor::new(
+                "an RFC 3339 UTC timestamp such as \"2023-11-05T23:59:59Z\"",
+            ))
+        } else {
+            let (secs, nanos) = <(i64, u32)>::deserialize(deserializer)?;
+            if nanos >= 1_000_000_000 {
+                return Err(de::Error::custom(format_args!(
+                    "nanoseconds {} out of range",
+                    nanos
+                )));
+            }
+            DateTime::from_unix_timestamp(secs, nanos as i32).map_err(|e| {
+                de::Error::custom(format_args!("timestamp {} out of range: {:?}", secs, e))
+            })
+        }
+    }
+}
+
+/// Parses a string field with the target type's `FromStr`.
+struct FromStrVisitor<T> {
+    expecting: &'static str,
+    marker: PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    fn new(expecting: &'static str) -> Self {
+        FromStrVisitor {
+            expecting,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: FromStr> Visitor<'_> for FromStrVisitor<T>
+where
+    T::Err: fmt::Debug,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        s.parse()
+            .map_err(|e| E::custom(format_args!("invalid value {:?}: {:?}", s, e)))
+    }
+}
+
+/// Serialize a [`Duration`](crate::Duration) as an ISO 8601 duration string
+/// (e.g. `"PT1H30M"`) and parse it back with
+/// [`Duration::parse_iso8601`](crate::Duration::parse_iso8601).
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "fasttime::serde::duration_iso8601")]
+///     timeout: Duration,
+/// }
+/// ```
+pub mod duration_iso8601 {
+    use core::fmt;
+
+    use ::serde::de::{self, Visitor};
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::Duration;
+
+    pub fn serialize<S: Serializer>(dur: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:#}", dur))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        deserializer.deserialize_str(IsoDurationVisitor)
+    }
+
+    struct IsoDurationVisitor;
+
+    impl Visitor<'_> for IsoDurationVisitor {
+        type Value = Duration;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an ISO 8601 duration string such as \"PT1H30M\"")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<Duration, E> {
+            Duration::parse_iso8601(s)
+                .map_err(|e| E::custom(format_args!("invalid ISO 8601 duration {:?}: {:?}", s, e)))
+        }
+    }
+}
+
+/// Serialize an [`OffsetDateTime`](crate::OffsetDateTime) as an RFC 3339
+/// string using its `Display` form and parse it back with `FromStr`.
+///
+/// The offset is kept as written: `+00:00` and `Z` come back as `Z`, while
+/// `-00:00` (unknown local offset) survives the round trip.
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "fasttime::serde::rfc3339")]
+///     at: OffsetDateTime,
+/// }
+/// ```
+pub mod rfc3339 {
+    use core::fmt;
+
+    use ::serde::de::{self, Visitor};
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::OffsetDateTime;
+
+    pub fn serialize<S: Serializer>(
+        odt: &OffsetDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(odt)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        deserializer.deserialize_str(Rfc3339Visitor)
+    }
+
+    struct Rfc3339Visitor;
+
+    impl Visitor<'_> for Rfc3339Visitor {
+        type Value = OffsetDateTime;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an RFC 3339 timestamp such as \"2023-11-05T23:59:59+02:00\"")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<OffsetDateTime, E> {
+            s.parse()
+                .map_err(|_| E::custom(format_args!("invalid RFC 3339 timestamp {:?}", s)))
+        }
+    }
+}
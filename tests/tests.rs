@@ -3,9 +3,45 @@
 #[cfg(test)]
 mod tests {
     use fasttime::{
-        parse_rfc3339_offset, Date, DateError, DateTime, Duration, OffsetDateTime, Time, TimeError,
-        UtcOffset, Weekday,
+        iso_weeks_in_year, parse_rfc3339_offset, CalendarUnit, Date, DateError, DateTime, Duration,
+        DurationError, DurationParseError, DurationParts, Month, OffsetDateTime, ParseError, Time,
+        TimeError, UtcOffset, UtcOffsetError, Weekday,
     };
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    /// Parse a UTC `DateTime` literal, panicking on bad test input.
+    fn dt(s: &str) -> DateTime {
+        s.parse().unwrap()
+    }
+
+    /// Build a `Date`, panicking on bad test input.
+    fn date(y: i32, m: u8, d: u8) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    /// Parse an `OffsetDateTime` literal, panicking on bad test input.
+    fn odt(s: &str) -> OffsetDateTime {
+        s.parse().unwrap()
+    }
+
+    /// Hash a value with the standard library's default hasher.
+    fn hash_of<T: Hash>(v: &T) -> u64 {
+        let mut h = DefaultHasher::new();
+        v.hash(&mut h);
+        h.finish()
+    }
+
+    /// Simple LCG so randomized tests are deterministic without extra dependencies.
+    fn lcg(seed: u64) -> impl FnMut() -> u64 {
+        let mut state = seed;
+        move || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            state
+        }
+    }
 
     #[test]
     fn date_epoch_and_neighbors() {
@@ -41,28 +77,2250 @@ mod tests {
     }
 
     #[test]
-    fn datetime_unix_round_trip() {
-        let date = Date::from_ymd(2024, 5, 17).unwrap();
-        let time = Time::from_hms_nano(12, 34, 56, 123_456_789).unwrap();
-        let dt = DateTime::new(date, time);
-        let secs = dt.unix_timestamp();
-        let nanos = dt.time.nanosecond as i32;
-        let rt = DateTime::from_unix_timestamp(secs, nanos).unwrap();
-        assert_eq!(dt, rt);
+    fn compact_i32_days() {
+        let d = Date::from_ymd(1975, 6, 15).unwrap();
+        let days = d.to_days_i32().unwrap();
+        assert_eq!(days as i64, d.days_since_unix_epoch());
+        assert_eq!(Date::from_days_i32(days).unwrap(), d);
+
+        let last = Date::from_days_i32(i32::MAX).unwrap();
+        assert_eq!(last.to_days_i32(), Ok(i32::MAX));
+        let first = Date::from_days_i32(i32::MIN).unwrap();
+        assert_eq!(first.to_days_i32(), Ok(i32::MIN));
+        assert_eq!(
+            last.add_days(1).unwrap().to_days_i32(),
+            Err(DateError::OutOfRange)
+        );
+
+        assert_eq!(Date::MAX.to_days_i32(), Err(DateError::OutOfRange));
+        assert_eq!(Date::MIN.to_days_i32(), Err(DateError::OutOfRange));
+    }
+
+    #[test]
+    fn rata_die_round_trip() {
+        assert_eq!(date(1, 1, 1).rata_die(), 1);
+        assert_eq!(date(1970, 1, 1).rata_die(), 719_163);
+        assert_eq!(date(0, 12, 31).rata_die(), 0);
+        assert_eq!(date(2000, 1, 1).rata_die(), 730_120);
+
+        for day in [
+            date(1, 1, 1),
+            date(1970, 1, 1),
+            date(-400, 3, 1),
+            date(2024, 2, 29),
+        ] {
+            assert_eq!(Date::from_rata_die(day.rata_die()), Ok(day));
+        }
+        assert_eq!(Date::from_rata_die(i64::MIN), Err(DateError::OutOfRange));
+    }
+
+    #[test]
+    fn packed_layouts() {
+        let d = Date::from_ymd(2023, 11, 5).unwrap();
+        // Layout is stable: year << 9 | month << 5 | day.
+        assert_eq!(d.to_packed(), (2023 << 9) | (11 << 5) | 5);
+        assert_eq!(d.to_packed(), 1_036_133);
+        assert_eq!(Date::from_packed(d.to_packed()), Ok(d));
+
+        for date in [Date::MIN, Date::MAX, Date::from_ymd(-4, 2, 29).unwrap()] {
+            assert_eq!(Date::from_packed(date.to_packed()), Ok(date));
+        }
+        assert!(Date::MIN.to_packed() < d.to_packed());
+        assert!(d.to_packed() < Date::MAX.to_packed());
+
+        assert_eq!(Date::from_packed(0), Err(DateError::MonthOutOfRange));
+        assert_eq!(Date::from_packed(i64::MAX), Err(DateError::OutOfRange));
+
+        let t = Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap();
+        assert_eq!(t.to_packed_nanos(), 86_399_999_999_999);
+        assert_eq!(Time::from_packed_nanos(t.to_packed_nanos()), Ok(t));
+        assert_eq!(
+            Time::from_packed_nanos(86_400_000_000_000),
+            Err(TimeError::InvalidTime)
+        );
+    }
+
+    #[test]
+    fn from_ymd_unchecked_accepts_valid_dates() {
+        const LEAP_DAY: Date = Date::from_ymd_unchecked(2024, 2, 29);
+        assert_eq!(LEAP_DAY, Date::from_ymd(2024, 2, 29).unwrap());
+    }
+
+    #[test]
+    fn from_ymd_const_in_const_context() {
+        const Y2K: Option<Date> = Date::from_ymd_const(2000, 1, 1);
+        const Y2K_DAYS: i64 = match Y2K {
+            Some(d) => d.days_since_unix_epoch(),
+            None => panic!("invalid date"),
+        };
+        assert_eq!(Y2K_DAYS, 10_957);
+        assert_eq!(Y2K, Date::from_ymd(2000, 1, 1).ok());
+
+        for (y, m, d) in [
+            (2023, 2, 29),
+            (2024, 0, 1),
+            (2024, 13, 1),
+            (2024, 4, 31),
+            (2024, 1, 0),
+        ] {
+            assert_eq!(Date::from_ymd_const(y, m, d), None);
+            assert!(Date::from_ymd(y, m, d).is_err());
+        }
+        assert!(Date::from_ymd_const(2024, 2, 29).is_some());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn from_ymd_unchecked_catches_day_past_month_end() {
+        let _ = Date::from_ymd_unchecked(2023, 2, 30);
+    }
+
+    #[test]
+    fn chunk_by_month_clips_partial_months() {
+        let chunks: Vec<_> = Date::chunk_by_month(date(2023, 11, 20), date(2024, 1, 10)).collect();
+        assert_eq!(
+            chunks,
+            vec![
+                (date(2023, 11, 20), date(2023, 12, 1)),
+                (date(2023, 12, 1), date(2024, 1, 1)),
+                (date(2024, 1, 1), date(2024, 1, 10)),
+            ]
+        );
+
+        let single: Vec<_> = Date::chunk_by_month(date(2024, 2, 3), date(2024, 2, 4)).collect();
+        assert_eq!(single, vec![(date(2024, 2, 3), date(2024, 2, 4))]);
+        assert_eq!(
+            Date::chunk_by_month(date(2024, 2, 3), date(2024, 2, 3)).count(),
+            0
+        );
+        assert_eq!(
+            Date::chunk_by_month(date(2024, 3, 1), date(2024, 2, 1)).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn add_months_clamps_day() {
+        assert_eq!(date(2024, 1, 31).add_months(1), Ok(date(2024, 2, 29)));
+        assert_eq!(date(2023, 1, 31).add_months(1), Ok(date(2023, 2, 28)));
+        assert_eq!(date(2024, 3, 31).add_months(-1), Ok(date(2024, 2, 29)));
+        assert_eq!(date(2024, 11, 15).add_months(3), Ok(date(2025, 2, 15)));
+        assert_eq!(date(2024, 1, 15).add_months(-13), Ok(date(2022, 12, 15)));
+        assert_eq!(date(2024, 5, 5).add_months(0), Ok(date(2024, 5, 5)));
+        assert_eq!(Date::MAX.add_months(1), Err(DateError::OutOfRange));
+        assert_eq!(Date::MIN.add_months(-1), Err(DateError::OutOfRange));
+        assert_eq!(
+            date(2024, 1, 1).add_months(i64::MAX),
+            Err(DateError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn date_clamp() {
+        let (min, max) = (date(2024, 1, 1), date(2024, 12, 31));
+        assert_eq!(date(2023, 12, 31).clamp(min, max), min);
+        assert_eq!(date(2025, 1, 1).clamp(min, max), max);
+        assert_eq!(date(2024, 2, 29).clamp(min, max), date(2024, 2, 29));
+        assert_eq!(min.clamp(min, max), min);
+        assert_eq!(Date::MAX.clamp(min, min), min);
+    }
+
+    #[test]
+    #[should_panic]
+    fn date_clamp_rejects_inverted_range() {
+        let _ = date(2024, 6, 1).clamp(date(2024, 12, 31), date(2024, 1, 1));
+    }
+
+    #[test]
+    fn years_between_counts_completed_years() {
+        let born = date(2000, 3, 1);
+        assert_eq!(born.years_between(date(2023, 2, 28)), 22);
+        assert_eq!(born.years_between(date(2023, 3, 1)), 23);
+        assert_eq!(born.years_between(born), 0);
+        assert_eq!(date(2023, 3, 1).years_between(born), -23);
+        assert_eq!(date(2023, 2, 28).years_between(born), -22);
+
+        // Feb 29th birthdays fall on Feb 28th in common years.
+        let leapling = date(2004, 2, 29);
+        assert_eq!(leapling.years_between(date(2023, 2, 27)), 18);
+        assert_eq!(leapling.years_between(date(2023, 2, 28)), 19);
+        assert_eq!(leapling.years_between(date(2024, 2, 28)), 19);
+        assert_eq!(leapling.years_between(date(2024, 2, 29)), 20);
+
+        assert_eq!(Date::MIN.years_between(Date::MAX), i32::MAX);
+        assert_eq!(Date::MAX.years_between(Date::MIN), -i32::MAX);
+    }
+
+    #[test]
+    fn age_on_reference_date() {
+        let born = date(1990, 7, 15);
+        assert_eq!(born.age_on(date(2024, 7, 14)), Some(33));
+        assert_eq!(born.age_on(date(2024, 7, 15)), Some(34));
+        assert_eq!(born.age_on(born), Some(0));
+        assert_eq!(born.age_on(date(1990, 7, 14)), None);
+    }
+
+    #[test]
+    fn saturating_add_duration_clamps_to_extremes() {
+        let dt: DateTime = "2023-11-05T12:00:00Z".parse().unwrap();
+        assert_eq!(
+            dt.saturating_add_duration(Duration::seconds(90)),
+            dt.add_duration(Duration::seconds(90)).unwrap()
+        );
+        assert_eq!(dt.saturating_add_duration(Duration::MAX), DateTime::MAX);
+        assert_eq!(dt.saturating_add_duration(Duration::MIN), DateTime::MIN);
+        assert_eq!(
+            DateTime::MAX.saturating_add_duration(Duration::nanoseconds(1)),
+            DateTime::MAX
+        );
+        assert_eq!(
+            DateTime::MIN.saturating_add_duration(Duration::nanoseconds(-1)),
+            DateTime::MIN
+        );
+        assert_eq!(DateTime::MAX.date, Date::MAX);
+        assert_eq!(DateTime::MIN.date, Date::MIN);
+        assert_eq!(
+            DateTime::MAX.add_duration(Duration::nanoseconds(1)),
+            Err(DateError::OutOfRange)
+        );
+        assert_eq!(dt.add_duration(Duration::MAX), Err(DateError::OutOfRange));
+
+        let plus_two = UtcOffset::from_whole_hours(2).unwrap();
+        let odt = OffsetDateTime::from_utc(dt, plus_two);
+        let clamped = odt.saturating_add_duration(Duration::MIN);
+        assert_eq!(clamped.utc, DateTime::MIN);
+        assert_eq!(clamped.offset, plus_two);
+        assert_eq!(
+            odt.saturating_add_duration(Duration::seconds(1)),
+            odt.add_duration(Duration::seconds(1)).unwrap()
+        );
+    }
+
+    #[test]
+    fn datetime_checked_add_unit() {
+        let start = dt("2024-01-31T10:30:00Z");
+
+        assert_eq!(
+            start.checked_add_unit(CalendarUnit::Days(1)),
+            Ok(dt("2024-02-01T10:30:00Z"))
+        );
+        assert_eq!(
+            start.checked_add_unit(CalendarUnit::Days(-31)),
+            Ok(dt("2023-12-31T10:30:00Z"))
+        );
+        assert_eq!(
+            start.checked_add_unit(CalendarUnit::Weeks(2)),
+            Ok(dt("2024-02-14T10:30:00Z"))
+        );
+        // Month-end clamping, in both directions.
+        assert_eq!(
+            start.checked_add_unit(CalendarUnit::Months(1)),
+            Ok(dt("2024-02-29T10:30:00Z"))
+        );
+        assert_eq!(
+            dt("2024-03-31T10:30:00Z").checked_add_unit(CalendarUnit::Months(-1)),
+            Ok(dt("2024-02-29T10:30:00Z"))
+        );
+        assert_eq!(
+            dt("2024-02-29T10:30:00Z").checked_add_unit(CalendarUnit::Years(1)),
+            Ok(dt("2025-02-28T10:30:00Z"))
+        );
+        assert_eq!(
+            dt("2024-02-29T10:30:00Z").checked_add_unit(CalendarUnit::Years(4)),
+            Ok(dt("2028-02-29T10:30:00Z"))
+        );
+
+        assert_eq!(
+            start.checked_add_unit(CalendarUnit::Weeks(i64::MAX)),
+            Err(DateError::OutOfRange)
+        );
+        assert_eq!(
+            start.checked_add_unit(CalendarUnit::Years(i32::MAX)),
+            Err(DateError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn monthly_clamps_and_rolls_over() {
+        let dates: Vec<_> = Date::monthly(date(2023, 11, 30), 31).take(6).collect();
+        assert_eq!(
+            dates,
+            vec![
+                date(2023, 11, 30),
+                date(2023, 12, 31),
+                date(2024, 1, 31),
+                date(2024, 2, 29),
+                date(2024, 3, 31),
+                date(2024, 4, 30),
+            ]
+        );
+
+        // Starts at the first occurrence on or after `start`.
+        let next: Vec<_> = Date::monthly(date(2024, 1, 20), 15).take(2).collect();
+        assert_eq!(next, vec![date(2024, 2, 15), date(2024, 3, 15)]);
+        let same: Vec<_> = Date::monthly(date(2024, 1, 15), 15).take(1).collect();
+        assert_eq!(same, vec![date(2024, 1, 15)]);
+
+        assert_eq!(Date::monthly(date(2024, 1, 1), 0).next(), None);
+        assert_eq!(Date::monthly(date(2024, 1, 1), 32).next(), None);
+        let last: Vec<_> = Date::monthly(date(i32::MAX, 11, 1), 31).collect();
+        assert_eq!(last, vec![date(i32::MAX, 11, 30), Date::MAX]);
+    }
+
+    #[test]
+    fn iter_days_back_crosses_month_boundary() {
+        let days: Vec<_> = Date::iter_days_back(date(2024, 3, 2)).take(5).collect();
+        assert_eq!(
+            days,
+            vec![
+                date(2024, 3, 2),
+                date(2024, 3, 1),
+                date(2024, 2, 29),
+                date(2024, 2, 28),
+                date(2024, 2, 27),
+            ]
+        );
+
+        let tail: Vec<_> = Date::iter_days_back(Date::MIN.succ().unwrap()).collect();
+        assert_eq!(tail, vec![Date::MIN.succ().unwrap(), Date::MIN]);
+    }
+
+    #[test]
+    fn add_days_overflow_variants() {
+        let d = Date::from_ymd(2024, 1, 1).unwrap();
+        assert_eq!(d.checked_add_days(366), Date::from_ymd(2025, 1, 1).ok());
+        assert_eq!(
+            d.saturating_add_days(-1),
+            Date::from_ymd(2023, 12, 31).unwrap()
+        );
+
+        assert_eq!(d.checked_add_days(i64::MAX), None);
+        assert_eq!(d.checked_add_days(i64::MIN), None);
+        assert_eq!(Date::MAX.checked_add_days(i64::MAX), None);
+        assert_eq!(Date::MIN.checked_add_days(i64::MIN), None);
+        assert_eq!(d.add_days(i64::MAX), Err(DateError::OutOfRange));
+        assert_eq!(Date::MAX.add_days(1), Err(DateError::OutOfRange));
+
+        assert_eq!(d.saturating_add_days(i64::MAX), Date::MAX);
+        assert_eq!(d.saturating_add_days(i64::MIN), Date::MIN);
+        assert_eq!(Date::MAX.saturating_add_days(i64::MAX), Date::MAX);
+        assert_eq!(Date::MIN.saturating_add_days(i64::MIN), Date::MIN);
+
+        assert_eq!(d.sub_days(1), Date::from_ymd(2023, 12, 31));
+        assert_eq!(d.sub_days(-366), Date::from_ymd(2025, 1, 1));
+        assert_eq!(d.checked_sub_days(0), Some(d));
+        assert_eq!(d.sub_days(i64::MIN), Err(DateError::OutOfRange));
+        assert_eq!(d.sub_days(i64::MAX), Err(DateError::OutOfRange));
+        assert_eq!(Date::MAX.checked_sub_days(i64::MIN), None);
+        assert_eq!(Date::MIN.checked_sub_days(i64::MAX), None);
+        assert_eq!(Date::MIN.sub_days(1), Err(DateError::OutOfRange));
+        assert_eq!(Date::MAX.sub_days(-1), Err(DateError::OutOfRange));
+
+        assert_eq!(
+            Date::from_days_since_unix_epoch(i64::MAX),
+            Err(DateError::OutOfRange)
+        );
+        assert_eq!(
+            Date::from_days_since_unix_epoch(i64::MIN),
+            Err(DateError::OutOfRange)
+        );
+        let last = Date::MAX.days_since_unix_epoch();
+        assert_eq!(Date::from_days_since_unix_epoch(last), Ok(Date::MAX));
+        let first = Date::MIN.days_since_unix_epoch();
+        assert_eq!(Date::from_days_since_unix_epoch(first), Ok(Date::MIN));
+    }
+
+    #[test]
+    fn succ_and_pred() {
+        assert_eq!(date(2024, 2, 28).succ(), Ok(date(2024, 2, 29)));
+        assert_eq!(date(2024, 2, 29).succ(), Ok(date(2024, 3, 1)));
+        assert_eq!(date(2023, 12, 31).succ(), Ok(date(2024, 1, 1)));
+        assert_eq!(date(2023, 3, 1).pred(), Ok(date(2023, 2, 28)));
+        assert_eq!(date(2024, 1, 1).pred(), Ok(date(2023, 12, 31)));
+
+        assert_eq!(Date::MAX.succ(), Err(DateError::OutOfRange));
+        assert_eq!(Date::MIN.pred(), Err(DateError::OutOfRange));
+        assert_eq!(Date::MAX.succ_opt(), None);
+        assert_eq!(Date::MIN.pred_opt(), None);
+        assert_eq!(
+            Date::MAX.pred_opt().and_then(Date::succ_opt),
+            Some(Date::MAX)
+        );
+
+        let dt: DateTime = "2024-02-29T12:00:00Z".parse().unwrap();
+        assert_eq!(dt.next_day().unwrap().to_string(), "2024-03-01T12:00:00Z");
+        assert_eq!(
+            dt.previous_day().unwrap().to_string(),
+            "2024-02-28T12:00:00Z"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn time_format_with_precision() {
+        let t = Time::from_hms_nano(9, 5, 7, 123_456_789).unwrap();
+        assert_eq!(t.format_with_precision(0), "09:05:07");
+        assert_eq!(t.format_with_precision(3), "09:05:07.123");
+        assert_eq!(t.format_with_precision(6), "09:05:07.123456");
+        assert_eq!(t.format_with_precision(9), "09:05:07.123456789");
+        assert_eq!(t.format_with_precision(12), "09:05:07.123456789");
+
+        let whole = Time::from_hms_nano(12, 0, 0, 0).unwrap();
+        assert_eq!(whole.format_with_precision(3), "12:00:00.000");
+
+        // Truncation never rolls over into the next second.
+        let late = Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap();
+        assert_eq!(late.format_with_precision(3), "23:59:59.999");
+
+        let mut buf = String::new();
+        t.format_with_precision_into(&mut buf, 1).unwrap();
+        assert_eq!(buf, "09:05:07.1");
+    }
+
+    #[test]
+    fn time_rounding_half_up() {
+        let t = |s: &str| s.parse::<Time>().unwrap();
+
+        assert_eq!(t("12:00:00.4").round_to_second(), (t("12:00:00"), false));
+        assert_eq!(t("12:00:00.5").round_to_second(), (t("12:00:01"), false));
+        assert_eq!(t("12:59:59.5").round_to_second(), (t("13:00:00"), false));
+        assert_eq!(
+            t("23:59:59.499999999").round_to_second(),
+            (t("23:59:59"), false)
+        );
+        assert_eq!(t("23:59:59.5").round_to_second(), (t("00:00:00"), true));
+
+        assert_eq!(
+            t("12:00:00.1234995").round_to_millis(),
+            (t("12:00:00.123"), false)
+        );
+        assert_eq!(
+            t("12:00:00.1235").round_to_millis(),
+            (t("12:00:00.124"), false)
+        );
+        assert_eq!(t("23:59:59.9995").round_to_millis(), (t("00:00:00"), true));
+
+        assert_eq!(
+            t("12:00:00.123456499").round_to_micros(),
+            (t("12:00:00.123456"), false)
+        );
+        assert_eq!(
+            t("12:00:00.1234565").round_to_micros(),
+            (t("12:00:00.123457"), false)
+        );
+        assert_eq!(
+            t("23:59:59.9999995").round_to_micros(),
+            (t("00:00:00"), true)
+        );
+
+        assert_eq!(t("08:30:00").round_to_second(), (t("08:30:00"), false));
+    }
+
+    #[test]
+    fn time_parse_with_offset() {
+        let (t, off) = Time::parse_with_offset("23:59:59+02:00").unwrap();
+        assert_eq!(t, Time::from_hms_nano(23, 59, 59, 0).unwrap());
+        assert_eq!(off.as_seconds(), 7200);
+
+        let (t, off) = Time::parse_with_offset("23:59:59.5Z").unwrap();
+        assert_eq!(t, Time::from_hms_nano(23, 59, 59, 500_000_000).unwrap());
+        assert!(off.is_utc());
+
+        let (_, off) = Time::parse_with_offset("08:00:00-05:30").unwrap();
+        assert_eq!(off.as_seconds(), -(5 * 3600 + 30 * 60));
+
+        assert_eq!(
+            Time::parse_with_offset("23:59:59"),
+            Err(TimeError::InvalidTime)
+        );
+        assert_eq!(
+            Time::parse_with_offset("23:59:59+25:00"),
+            Err(TimeError::InvalidTime)
+        );
+        assert_eq!(Time::parse_with_offset("Z"), Err(TimeError::InvalidTime));
+        assert_eq!(Time::parse_with_offset(""), Err(TimeError::InvalidTime));
+    }
+
+    #[test]
+    fn from_ymd_reports_offending_field() {
+        assert_eq!(Date::from_ymd(2024, 0, 1), Err(DateError::MonthOutOfRange));
+        assert_eq!(Date::from_ymd(2024, 13, 1), Err(DateError::MonthOutOfRange));
+        assert_eq!(Date::from_ymd(2024, 13, 0), Err(DateError::MonthOutOfRange));
+        assert_eq!(Date::from_ymd(2024, 1, 0), Err(DateError::DayOutOfRange));
+        assert_eq!(Date::from_ymd(2024, 1, 32), Err(DateError::DayOutOfRange));
+        assert_eq!(Date::from_ymd(2023, 2, 29), Err(DateError::InvalidDate));
+        assert_eq!(Date::from_ymd(2024, 4, 31), Err(DateError::InvalidDate));
+
+        assert_eq!(
+            "2024-13-01".parse::<Date>(),
+            Err(DateError::MonthOutOfRange)
+        );
+        assert_eq!("2024-01-32".parse::<Date>(), Err(DateError::DayOutOfRange));
+        assert_eq!("2024-02-30".parse::<Date>(), Err(DateError::InvalidDate));
+        assert_eq!("2024-1x-01".parse::<Date>(), Err(DateError::InvalidDate));
+        assert_eq!("2024-100-01".parse::<Date>(), Err(DateError::InvalidDate));
+    }
+
+    #[test]
+    fn month_numbers_and_names() {
+        assert_eq!(Month::January.number(), 1);
+        assert_eq!(Month::December.number(), 12);
+        for n in 1..=12 {
+            assert_eq!(Month::from_number(n).unwrap().number(), n);
+        }
+        assert_eq!(Month::from_number(0), None);
+        assert_eq!(Month::from_number(13), None);
+
+        assert_eq!("November".parse(), Ok(Month::November));
+        assert_eq!("nov".parse(), Ok(Month::November));
+        assert_eq!("SEP".parse(), Ok(Month::September));
+        assert_eq!("may".parse(), Ok(Month::May));
+        assert_eq!("Sept".parse::<Month>(), Err(DateError::InvalidDate));
+        assert_eq!("".parse::<Month>(), Err(DateError::InvalidDate));
+
+        assert_eq!(Month::January.long_name(), "January");
+        assert_eq!(Month::September.short_name(), "Sep");
+        for n in 1..=12 {
+            let month = Month::from_number(n).unwrap();
+            assert_eq!(month.long_name().parse(), Ok(month));
+            assert_eq!(month.short_name().parse(), Ok(month));
+        }
+    }
+
+    #[test]
+    fn weekday_names() {
+        assert_eq!(Weekday::Monday.long_name(), "Monday");
+        assert_eq!(Weekday::Monday.short_name(), "Mon");
+        assert_eq!(Weekday::Wednesday.long_name(), "Wednesday");
+        assert_eq!(Weekday::Thursday.short_name(), "Thu");
+        assert_eq!(Weekday::Sunday.short_name(), "Sun");
+        let date = Date::from_ymd(2023, 11, 5).unwrap();
+        assert_eq!(date.weekday().long_name(), "Sunday");
+    }
+
+    #[test]
+    fn weekday_and_month_display() {
+        let date = Date::from_ymd(2023, 11, 5).unwrap();
+        assert_eq!(date.weekday().to_string(), "Sunday");
+        assert_eq!(format!("{:?}", date.weekday()), "Sunday");
+        assert_eq!(format!("[{:<9}]", Weekday::Monday), "[Monday   ]");
+        assert_eq!(Month::November.to_string(), "November");
+        assert_eq!(format!("[{:>5}]", Month::May), "[  May]");
+        assert_eq!(format!("{:.3}", Month::September), "Sep");
+    }
+
+    #[test]
+    fn basic_iso8601_parsing() {
+        let date = Date::from_ymd(2023, 11, 5).unwrap();
+        assert_eq!(Date::parse_basic("20231105"), Ok(date));
+        assert_eq!(Date::parse_basic("00010101"), Date::from_ymd(1, 1, 1));
+        assert_eq!(
+            Date::parse_basic("20231305"),
+            Err(DateError::MonthOutOfRange)
+        );
+        assert_eq!(Date::parse_basic("20230229"), Err(DateError::InvalidDate));
+        for bad in [
+            "2023110",
+            "202311055",
+            "2023-11-05",
+            "2023110x",
+            "+2023110",
+            "",
+        ] {
+            assert_eq!(
+                Date::parse_basic(bad),
+                Err(DateError::InvalidDate),
+                "{bad:?}"
+            );
+        }
+
+        let time = Time::from_hms_nano(23, 59, 59, 0).unwrap();
+        assert_eq!(Time::parse_basic("235959"), Ok(time));
+        assert_eq!(
+            Time::parse_basic("235959.125"),
+            Time::from_hms_nano(23, 59, 59, 125_000_000)
+        );
+        for bad in [
+            "240000", "236000", "235960", "23:59:59", "2359", "235959.", "",
+        ] {
+            assert_eq!(
+                Time::parse_basic(bad),
+                Err(TimeError::InvalidTime),
+                "{bad:?}"
+            );
+        }
+
+        let dt = DateTime::parse_basic("20231105T235959Z").unwrap();
+        assert_eq!(dt, DateTime::new(date, time));
+        assert_eq!(
+            DateTime::parse_basic("20231105T235959.5z")
+                .unwrap()
+                .to_string(),
+            "2023-11-05T23:59:59.5Z"
+        );
+        assert_eq!(
+            DateTime::parse_basic("20231105 235959Z"),
+            Err(ParseError::InvalidFormat)
+        );
+        assert_eq!(
+            DateTime::parse_basic("20231105T235959"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            DateTime::parse_basic("20231135T235959Z"),
+            Err(ParseError::InvalidDate)
+        );
+        assert_eq!(
+            DateTime::parse_basic("20231105T245959Z"),
+            Err(ParseError::InvalidTime)
+        );
+        // The extended forms stay the default for `FromStr`.
+        assert!("20231105".parse::<Date>().is_err());
+        assert!("20231105T235959Z".parse::<DateTime>().is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn basic_iso8601_formatting() {
+        let dt: DateTime = "2023-11-05T23:59:59Z".parse().unwrap();
+        assert_eq!(dt.date.to_basic_iso(), "20231105");
+        assert_eq!(dt.time.to_basic_iso(), "235959");
+        assert_eq!(dt.to_basic_iso(), "20231105T235959Z");
+
+        let frac: DateTime = "0044-03-15T01:02:03.000000250Z".parse().unwrap();
+        assert_eq!(frac.to_basic_iso(), "00440315T010203.00000025Z");
+        let half = Time::from_hms_nano(0, 0, 0, 500_000_000).unwrap();
+        assert_eq!(half.to_basic_iso(), "000000.5");
+
+        for s in ["20231105T235959Z", "19700101T000000.123456789Z"] {
+            assert_eq!(DateTime::parse_basic(s).unwrap().to_basic_iso(), s);
+        }
+    }
+
+    #[test]
+    fn lenient_parsing_trims_surrounding_whitespace() {
+        let date = Date::from_ymd(2023, 11, 5).unwrap();
+        for s in [
+            " 2023-11-05",
+            "2023-11-05 ",
+            "\t2023-11-05",
+            "  2023-11-05\r\n",
+        ] {
+            assert_eq!(Date::parse_lenient(s), Ok(date), "{s:?}");
+            assert!(s.parse::<Date>().is_err(), "strict parse accepted {s:?}");
+        }
+        assert!(Date::parse_lenient("2023 -11-05").is_err());
+        assert!(Date::parse_lenient("   ").is_err());
+
+        let time = Time::from_hms_nano(23, 59, 59, 500_000_000).unwrap();
+        for s in [" 23:59:59.5", "23:59:59.5\t", "\t 23:59:59.5\n"] {
+            assert_eq!(Time::parse_lenient(s), Ok(time), "{s:?}");
+            assert!(s.parse::<Time>().is_err(), "strict parse accepted {s:?}");
+        }
+        assert!(Time::parse_lenient("23: 59:59").is_err());
+    }
+
+    #[test]
+    fn date_parse_named() {
+        let expected = Date::from_ymd(2023, 11, 5).unwrap();
+        for s in [
+            "5 Nov 2023",
+            "05 Nov 2023",
+            "Nov 5, 2023",
+            "November 5 2023",
+            "november 5, 2023",
+            "5 NOVEMBER 2023",
+            "  Nov  5,\t2023 ",
+        ] {
+            assert_eq!(Date::parse_named(s), Ok(expected), "{s:?}");
+        }
+
+        assert_eq!(Date::parse_named("5 Foo 2023"), Err(DateError::InvalidDate));
+        assert_eq!(
+            Date::parse_named("Novem 5, 2023"),
+            Err(DateError::InvalidDate)
+        );
+        assert_eq!(
+            Date::parse_named("Nov 5 2023 12:00"),
+            Err(DateError::InvalidDate)
+        );
+        assert_eq!(Date::parse_named("Nov 2023"), Err(DateError::InvalidDate));
+        assert_eq!(
+            Date::parse_named("Feb 30, 2023"),
+            Err(DateError::InvalidDate)
+        );
+        assert_eq!(
+            Date::parse_named("Nov 5; 2023"),
+            Err(DateError::InvalidDate)
+        );
+    }
+
+    #[test]
+    fn from_ymd_or_clamp_reports_clamping() {
+        assert_eq!(
+            Date::from_ymd_or_clamp(2023, 2, 30),
+            (Date::from_ymd(2023, 2, 28).unwrap(), true)
+        );
+        assert_eq!(
+            Date::from_ymd_or_clamp(2024, 2, 30),
+            (Date::from_ymd(2024, 2, 29).unwrap(), true)
+        );
+        assert_eq!(
+            Date::from_ymd_or_clamp(2024, 2, 29),
+            (Date::from_ymd(2024, 2, 29).unwrap(), false)
+        );
+        assert_eq!(
+            Date::from_ymd_or_clamp(2024, 13, 0),
+            (Date::from_ymd(2024, 12, 1).unwrap(), true)
+        );
+        assert_eq!(
+            Date::from_ymd_or_clamp(2024, 4, 31),
+            (Date::from_ymd(2024, 4, 30).unwrap(), true)
+        );
+    }
+
+    #[test]
+    fn time_difference_and_abs_difference() {
+        let t = |h, m, s| Time::from_hms_nano(h, m, s, 0).unwrap();
+        let hours = |h: i64| Duration::seconds(h * 3600);
+        assert_eq!(t(23, 0, 0).difference(t(1, 0, 0)), hours(22));
+        assert_eq!(t(1, 0, 0).difference(t(23, 0, 0)), hours(-22));
+        assert_eq!(t(1, 0, 0).difference(t(1, 0, 0)), Duration::ZERO);
+        let last = Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap();
+        assert_eq!(
+            last.difference(t(0, 0, 0)),
+            Duration::nanoseconds(86_400_000_000_000 - 1)
+        );
+
+        assert_eq!(t(23, 0, 0).abs_difference(t(1, 0, 0)), hours(2));
+        assert_eq!(t(1, 0, 0).abs_difference(t(23, 0, 0)), hours(2));
+        assert_eq!(
+            t(9, 0, 0).abs_difference(t(17, 30, 0)),
+            hours(8) + Duration::seconds(1800)
+        );
+        assert_eq!(t(0, 0, 0).abs_difference(t(12, 0, 0)), hours(12));
+        assert_eq!(last.abs_difference(t(0, 0, 0)), Duration::nanoseconds(1));
+    }
+
+    #[test]
+    fn time_from_nanos_since_midnight_round_trips() {
+        for t in [
+            Time::from_hms_nano(0, 0, 0, 0).unwrap(),
+            Time::from_hms_nano(12, 34, 56, 789).unwrap(),
+            Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap(),
+        ] {
+            assert_eq!(
+                Time::from_nanos_since_midnight(t.nanos_since_midnight()),
+                Ok(t)
+            );
+        }
+        assert_eq!(
+            Time::from_nanos_since_midnight(86_400_000_000_000),
+            Err(TimeError::InvalidTime)
+        );
+        assert_eq!(
+            Time::from_nanos_since_midnight(u64::MAX),
+            Err(TimeError::InvalidTime)
+        );
+    }
+
+    #[test]
+    fn iso_weeks_in_year_counts() {
+        // 2020: leap year starting on Wednesday. 2026 and 2015: Thursday.
+        assert_eq!(Date::iso_weeks_in_year(2020), 53);
+        assert_eq!(Date::iso_weeks_in_year(2026), 53);
+        assert_eq!(iso_weeks_in_year(2015), 53);
+        // 2019 starts on Tuesday; 2025 on Wednesday but is not a leap year.
+        assert_eq!(Date::iso_weeks_in_year(2019), 52);
+        assert_eq!(iso_weeks_in_year(2025), 52);
+        assert_eq!(iso_weeks_in_year(2024), 52);
+
+        // 71 of every 400 years have 53 weeks.
+        let long = (2000..2400).filter(|&y| iso_weeks_in_year(y) == 53).count();
+        assert_eq!(long, 71);
+        assert!(matches!(iso_weeks_in_year(i32::MIN), 52 | 53));
+        assert!(matches!(iso_weeks_in_year(i32::MAX), 52 | 53));
+    }
+
+    #[test]
+    fn week_of_year_with_configurable_start() {
+        // 2023-01-01 is a Sunday.
+        assert_eq!(date(2023, 1, 1).week_of_year(Weekday::Sunday), 1);
+        assert_eq!(date(2023, 1, 7).week_of_year(Weekday::Sunday), 1);
+        assert_eq!(date(2023, 1, 8).week_of_year(Weekday::Sunday), 2);
+        assert_eq!(date(2023, 1, 1).week_of_year(Weekday::Monday), 1);
+        assert_eq!(date(2023, 1, 2).week_of_year(Weekday::Monday), 2);
+        assert_eq!(date(2023, 12, 31).week_of_year(Weekday::Sunday), 53);
+        assert_eq!(date(2023, 12, 31).week_of_year(Weekday::Monday), 53);
+
+        // 2024-01-01 is a Monday, so both conventions agree until Sunday.
+        assert_eq!(date(2024, 1, 6).week_of_year(Weekday::Sunday), 1);
+        assert_eq!(date(2024, 1, 7).week_of_year(Weekday::Sunday), 2);
+        assert_eq!(date(2024, 1, 7).week_of_year(Weekday::Monday), 1);
+
+        // 2000 is a leap year starting on Saturday: Dec 31 opens week 54.
+        assert_eq!(date(2000, 1, 1).week_of_year(Weekday::Sunday), 1);
+        assert_eq!(date(2000, 1, 2).week_of_year(Weekday::Sunday), 2);
+        assert_eq!(date(2000, 12, 30).week_of_year(Weekday::Sunday), 53);
+        assert_eq!(date(2000, 12, 31).week_of_year(Weekday::Sunday), 54);
+    }
+
+    #[test]
+    fn iso_week_dates() {
+        assert_eq!(
+            (date(2023, 11, 12).iso_year(), date(2023, 11, 12).iso_week()),
+            (2023, 45)
+        );
+        // Year-boundary reassignment in both directions.
+        assert_eq!(
+            (date(2021, 1, 3).iso_year(), date(2021, 1, 3).iso_week()),
+            (2020, 53)
+        );
+        assert_eq!(
+            (date(2024, 12, 30).iso_year(), date(2024, 12, 30).iso_week()),
+            (2025, 1)
+        );
+        assert_eq!(
+            (date(2026, 12, 31).iso_year(), date(2026, 12, 31).iso_week()),
+            (2026, 53)
+        );
+
+        assert_eq!(
+            Date::from_iso_week(2023, 45, Weekday::Sunday),
+            Ok(date(2023, 11, 12))
+        );
+        assert_eq!(
+            Date::from_iso_week(2020, 53, Weekday::Sunday),
+            Ok(date(2021, 1, 3))
+        );
+        assert_eq!(
+            Date::from_iso_week(2025, 1, Weekday::Monday),
+            Ok(date(2024, 12, 30))
+        );
+        assert_eq!(
+            Date::from_iso_week(2023, 53, Weekday::Monday),
+            Err(DateError::InvalidDate)
+        );
+        assert_eq!(
+            Date::from_iso_week(2023, 0, Weekday::Monday),
+            Err(DateError::InvalidDate)
+        );
+
+        // Date::MAX's last days belong to the next ISO year.
+        assert_eq!(Date::MAX.iso_year(), i32::MAX as i64 + 1);
+        assert_eq!(Date::MAX.iso_week(), 1);
+        assert_eq!(
+            Date::from_iso_week(Date::MAX.iso_year(), 1, Date::MAX.weekday()),
+            Ok(Date::MAX)
+        );
+        assert_eq!(
+            Date::from_iso_week(i32::MAX as i64 + 1, 1, Weekday::Wednesday),
+            Err(DateError::OutOfRange)
+        );
+        assert_eq!(Date::MIN.iso_year(), i32::MIN as i64);
+        assert_eq!(
+            Date::from_iso_week(i32::MIN as i64, 1, Weekday::Monday),
+            Err(DateError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn parse_iso_week_date_time() {
+        let week: DateTime = "2023-W45-7T12:00:00Z".parse().unwrap();
+        let calendar: DateTime = "2023-11-12T12:00:00Z".parse().unwrap();
+        assert_eq!(week, calendar);
+        assert_eq!(week.to_string(), "2023-11-12T12:00:00Z");
+        let date = week.date;
+        assert_eq!(
+            format!(
+                "{}-W{:02}-{}",
+                date.iso_year(),
+                date.iso_week(),
+                date.weekday().number_from_monday()
+            ),
+            "2023-W45-7"
+        );
+
+        assert_eq!(
+            "2020-W53-5".parse::<Date>(),
+            Ok(Date::from_ymd(2021, 1, 1).unwrap())
+        );
+        let odt: OffsetDateTime = "2020-W53-5T08:00:00+02:00".parse().unwrap();
+        assert_eq!(odt.utc.to_string(), "2021-01-01T06:00:00Z");
+
+        for bad in [
+            "2023-W54-1",
+            "2023-W00-1",
+            "2023-W45-8",
+            "2023-W45-0",
+            "2023-W5-1",
+            "2023-W45-",
+            "2019-W53-1",
+        ] {
+            assert_eq!(bad.parse::<Date>(), Err(DateError::InvalidDate), "{bad}");
+        }
+        assert!("2023-W45-7T25:00:00Z".parse::<DateTime>().is_err());
+    }
+
+    #[test]
+    fn with_setters_revalidate() {
+        let jan31 = Date::from_ymd(2024, 1, 31).unwrap();
+        assert_eq!(jan31.with_month(2), Err(DateError::InvalidDate));
+        assert_eq!(jan31.with_month(3), Date::from_ymd(2024, 3, 31));
+        assert_eq!(jan31.with_day(1), Date::from_ymd(2024, 1, 1));
+        assert_eq!(jan31.with_day(32), Err(DateError::DayOutOfRange));
+        assert_eq!(jan31.with_year(1999), Date::from_ymd(1999, 1, 31));
+
+        let leap = Date::from_ymd(2024, 2, 29).unwrap();
+        assert_eq!(leap.with_year(2023), Err(DateError::InvalidDate));
+        assert_eq!(leap.with_year(2028), Date::from_ymd(2028, 2, 29));
+
+        let t = Time::from_hms_nano(12, 30, 45, 5).unwrap();
+        assert_eq!(t.with_hour(23), Time::from_hms_nano(23, 30, 45, 5));
+        assert_eq!(t.with_minute(0), Time::from_hms_nano(12, 0, 45, 5));
+        assert_eq!(t.with_second(59), Time::from_hms_nano(12, 30, 59, 5));
+        assert_eq!(t.with_nanosecond(0), Time::from_hms_nano(12, 30, 45, 0));
+        assert_eq!(t.with_hour(24), Err(TimeError::InvalidTime));
+        assert_eq!(t.with_minute(60), Err(TimeError::InvalidTime));
+        assert_eq!(t.with_second(60), Err(TimeError::InvalidTime));
+        assert_eq!(
+            t.with_nanosecond(1_000_000_000),
+            Err(TimeError::InvalidTime)
+        );
+
+        let dt = DateTime::new(jan31, t);
+        assert_eq!(dt.with_date(leap), DateTime::new(leap, t));
+        let midnight = Time::from_hms_nano(0, 0, 0, 0).unwrap();
+        assert_eq!(dt.with_time(midnight), DateTime::new(jan31, midnight));
+        assert_eq!(
+            dt.with_nanosecond(0),
+            Ok(DateTime::new(
+                jan31,
+                Time::from_hms_nano(12, 30, 45, 0).unwrap()
+            ))
+        );
+        assert_eq!(
+            dt.with_nanosecond(999_999_999).map(|dt| dt.nanosecond()),
+            Ok(999_999_999)
+        );
+        assert_eq!(
+            dt.with_nanosecond(1_000_000_000),
+            Err(TimeError::InvalidTime)
+        );
+    }
+
+    #[test]
+    fn datetime_unix_round_trip() {
+        let date = Date::from_ymd(2024, 5, 17).unwrap();
+        let time = Time::from_hms_nano(12, 34, 56, 123_456_789).unwrap();
+        let dt = DateTime::new(date, time);
+        let secs = dt.unix_timestamp();
+        let nanos = dt.time.nanosecond as i32;
+        let rt = DateTime::from_unix_timestamp(secs, nanos).unwrap();
+        assert_eq!(dt, rt);
+    }
+
+    #[test]
+    fn datetime_unix_timestamp_f64() {
+        assert_eq!(
+            DateTime::from_unix_timestamp_f64(1_699_228_799.5),
+            Ok(dt("2023-11-05T23:59:59.5Z"))
+        );
+        assert_eq!(
+            DateTime::from_unix_timestamp_f64(-0.25),
+            Ok(dt("1969-12-31T23:59:59.75Z"))
+        );
+        assert_eq!(
+            DateTime::from_unix_timestamp_f64(-1.5),
+            Ok(dt("1969-12-31T23:59:58.5Z"))
+        );
+        assert_eq!(
+            DateTime::from_unix_timestamp_f64(0.0),
+            Ok(dt("1970-01-01T00:00:00Z"))
+        );
+        assert_eq!(
+            DateTime::from_unix_timestamp_f64(-0.0),
+            Ok(dt("1970-01-01T00:00:00Z"))
+        );
+        // Rounded to the nearest nanosecond, carrying into the next second.
+        assert_eq!(
+            DateTime::from_unix_timestamp_f64(0.000_000_001_4),
+            Ok(dt("1970-01-01T00:00:00.000000001Z"))
+        );
+        assert_eq!(
+            DateTime::from_unix_timestamp_f64(0.999_999_999_9),
+            Ok(dt("1970-01-01T00:00:01Z"))
+        );
+        // Present-day values keep roughly microsecond precision.
+        let present = DateTime::from_unix_timestamp_f64(1_699_228_799.123_456).unwrap();
+        assert_eq!(present.unix_timestamp(), 1_699_228_799);
+        assert!((present.time.nanosecond as i64 - 123_456_000).abs() < 1_000);
+
+        for bad in [
+            f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            1e19,
+            -1e19,
+            1e18,
+        ] {
+            assert_eq!(
+                DateTime::from_unix_timestamp_f64(bad),
+                Err(DateError::OutOfRange),
+                "{bad}"
+            );
+        }
+
+        assert_eq!(
+            dt("2023-11-05T23:59:59.5Z").unix_timestamp_f64(),
+            1_699_228_799.5
+        );
+        assert_eq!(dt("1969-12-31T23:59:59.75Z").unix_timestamp_f64(), -0.25);
+        let quarter = dt("2023-11-05T23:59:59.25Z");
+        assert_eq!(
+            DateTime::from_unix_timestamp_f64(quarter.unix_timestamp_f64()),
+            Ok(quarter)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn batch_unix_timestamp_conversions() {
+        let secs = [0, -1, 1_699_228_799, i64::MAX];
+        let dts = DateTime::from_unix_timestamps(&secs);
+        assert_eq!(dts.len(), secs.len());
+        for (&s, dt) in secs.iter().zip(&dts) {
+            assert_eq!(*dt, DateTime::from_unix_timestamp(s, 0));
+        }
+        assert_eq!(dts[3], Err(DateError::OutOfRange));
+
+        let ok: Vec<DateTime> = dts.into_iter().take(3).map(Result::unwrap).collect();
+        let mut out = [0i64; 3];
+        DateTime::to_unix_timestamps(&ok, &mut out);
+        assert_eq!(out, [0, -1, 1_699_228_799]);
+
+        assert!(DateTime::from_unix_timestamps(&[]).is_empty());
+        DateTime::to_unix_timestamps(&[], &mut []);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parse_all_keeps_per_item_results() {
+        let column = ["2023-11-05T23:59:59Z", "garbage", "1970-01-01T00:00:00Z"];
+        let parsed = fasttime::parse_all::<DateTime>(column);
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(parsed[0], "2023-11-05T23:59:59Z".parse());
+        assert_eq!(parsed[1], Err(ParseError::InvalidFormat));
+        assert_eq!(parsed[2], Ok(DateTime::from_unix_timestamp(0, 0).unwrap()));
+
+        let owned: Vec<String> = vec!["2024-02-29".into(), "2024-03-01".into()];
+        let dates = fasttime::try_parse_all::<Date>(owned.iter().map(String::as_str));
+        assert_eq!(
+            dates,
+            Ok(vec![
+                Date::from_ymd(2024, 2, 29).unwrap(),
+                Date::from_ymd(2024, 3, 1).unwrap()
+            ])
+        );
+        assert_eq!(
+            fasttime::try_parse_all::<Date>(["2024-02-29", "2023-02-29", "x"]),
+            Err(DateError::InvalidDate)
+        );
+        assert_eq!(fasttime::try_parse_all::<Time>([]), Ok(vec![]));
+        assert!(fasttime::parse_all::<Duration>([]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "lengths differ")]
+    fn batch_to_unix_timestamps_checks_lengths() {
+        let dt = DateTime::from_unix_timestamp(0, 0).unwrap();
+        DateTime::to_unix_timestamps(&[dt, dt], &mut [0i64; 1]);
+    }
+
+    #[test]
+    fn unix_32_bit_boundaries() {
+        let y2038 = dt("2038-01-19T03:14:07.999Z");
+        assert_eq!(y2038.to_unix_i32(), Ok(i32::MAX));
+        assert_eq!(
+            dt("2038-01-19T03:14:08Z").to_unix_i32(),
+            Err(DateError::OutOfRange)
+        );
+        assert_eq!(dt("1901-12-13T20:45:52Z").to_unix_i32(), Ok(i32::MIN));
+        assert_eq!(
+            dt("1901-12-13T20:45:51Z").to_unix_i32(),
+            Err(DateError::OutOfRange)
+        );
+
+        assert_eq!(dt("2106-02-07T06:28:15Z").to_unix_u32(), Ok(u32::MAX));
+        assert_eq!(
+            dt("2106-02-07T06:28:16Z").to_unix_u32(),
+            Err(DateError::OutOfRange)
+        );
+        assert_eq!(dt("1970-01-01T00:00:00Z").to_unix_u32(), Ok(0));
+        assert_eq!(
+            dt("1969-12-31T23:59:59Z").to_unix_u32(),
+            Err(DateError::OutOfRange)
+        );
+        assert_eq!(y2038.to_unix_u32(), Ok(i32::MAX as u32));
+
+        let odt: OffsetDateTime = "2038-01-19T05:14:07+02:00".parse().unwrap();
+        assert_eq!(odt.to_unix_i32(), Ok(i32::MAX));
+        assert_eq!(odt.to_unix_u32(), Ok(i32::MAX as u32));
+    }
+
+    #[test]
+    fn datetime_delegating_accessors() {
+        let dt: DateTime = "2024-03-01T07:08:09.000000042Z".parse().unwrap();
+        assert_eq!((dt.year(), dt.month(), dt.day()), (2024, 3, 1));
+        assert_eq!((dt.hour(), dt.minute(), dt.second()), (7, 8, 9));
+        assert_eq!(dt.nanosecond(), 42);
+        assert_eq!(dt.weekday(), Weekday::Friday);
+        assert_eq!(dt.weekday(), dt.date.weekday());
+        assert_eq!(dt.ordinal(), 61);
+    }
+
+    #[test]
+    fn datetime_with_date_and_nanos() {
+        let date = Date::from_ymd(2024, 2, 29).unwrap();
+        let dt = DateTime::with_date_and_nanos(date, 0).unwrap();
+        assert_eq!(dt.to_string(), "2024-02-29T00:00:00Z");
+
+        let last = DateTime::with_date_and_nanos(date, 86_399_999_999_999).unwrap();
+        assert_eq!(last.to_string(), "2024-02-29T23:59:59.999999999Z");
+        assert_eq!(last.time.nanos_since_midnight(), 86_399_999_999_999);
+
+        assert_eq!(
+            DateTime::with_date_and_nanos(date, 86_400_000_000_000),
+            Err(DateError::OutOfRange)
+        );
+        assert_eq!(
+            DateTime::with_date_and_nanos(date, u64::MAX),
+            Err(DateError::OutOfRange)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_iso_string_matches_display() {
+        let odt: OffsetDateTime = "2023-11-05T23:59:59.5+05:30".parse().unwrap();
+        let dt = odt.utc;
+        assert_eq!(dt.date.to_iso_string(), "2023-11-05");
+        assert_eq!(dt.time.to_iso_string(), "18:29:59.5");
+        assert_eq!(dt.to_iso_string(), "2023-11-05T18:29:59.5Z");
+        assert_eq!(odt.to_iso_string(), "2023-11-05T23:59:59.5+05:30");
+        assert_eq!(dt.to_iso_string(), dt.to_string());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn date_format_two_digit_year() {
+        let date = Date::from_ymd(2023, 11, 5).unwrap();
+        assert_eq!(date.format("%y").unwrap(), "23");
+        assert_eq!(date.format("%d/%m/%y").unwrap(), "05/11/23");
+        assert_eq!(date.format("%F").unwrap(), "2023-11-05");
+        assert_eq!(
+            Date::from_ymd(2005, 1, 1).unwrap().format("%y").unwrap(),
+            "05"
+        );
+        assert_eq!(
+            Date::from_ymd(-1, 1, 1).unwrap().format("%y").unwrap(),
+            "99"
+        );
+        assert_eq!(
+            date.format("%Y %H").unwrap_err(),
+            fasttime::FormatError::UnknownSpecifier('H')
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn time_format() {
+        let t = Time::from_hms_nano(7, 8, 9, 1_500).unwrap();
+        assert_eq!(t.format("%H:%M:%S.%f").unwrap(), "07:08:09.000001500");
+        assert_eq!(t.format("%Th").unwrap(), "07:08:09h");
+        assert_eq!(t.format("100%%").unwrap(), "100%");
+        assert_eq!(
+            t.format("%T %d").unwrap_err(),
+            fasttime::FormatError::UnknownSpecifier('d')
+        );
+    }
+
+    #[test]
+    fn date_parse_from_format() {
+        let parse = Date::parse_from_format;
+        assert_eq!(
+            parse("69-01-02", "%y-%m-%d"),
+            Ok(Date::from_ymd(1969, 1, 2).unwrap())
+        );
+        assert_eq!(parse("70-01-02", "%y-%m-%d").unwrap().year, 1970);
+        assert_eq!(parse("99-01-02", "%y-%m-%d").unwrap().year, 1999);
+        assert_eq!(parse("00-01-02", "%y-%m-%d").unwrap().year, 2000);
+        assert_eq!(parse("68-01-02", "%y-%m-%d").unwrap().year, 2068);
+
+        assert_eq!(
+            parse("05/11/2023", "%d/%m/%Y"),
+            Ok(Date::from_ymd(2023, 11, 5).unwrap())
+        );
+        assert_eq!(
+            parse("-0044-03-15", "%Y-%m-%d"),
+            Ok(Date::from_ymd(-44, 3, 15).unwrap())
+        );
+        assert_eq!(parse("100% 2023.11.05", "100%% %Y.%m.%d").unwrap().day, 5);
+        assert_eq!(
+            parse("20231105", "%Y%m%d"),
+            Ok(Date::from_ymd(2023, 11, 5).unwrap())
+        );
+        assert_eq!(
+            parse("-00440315", "%Y%m%d"),
+            Ok(Date::from_ymd(-44, 3, 15).unwrap())
+        );
+        assert_eq!(
+            parse("123456", "%Y-%m").unwrap_err(),
+            ParseError::InvalidFormat
+        );
+        assert_eq!(parse("2023110", "%Y%m%d"), Err(ParseError::InvalidFormat));
+        assert_eq!(parse("12023-11-05", "%Y-%m-%d").unwrap().year, 12023);
+
+        assert_eq!(
+            parse("2023-02-30", "%Y-%m-%d"),
+            Err(ParseError::InvalidDate)
+        );
+        assert_eq!(
+            parse("2023-11-05", "%Y/%m/%d"),
+            Err(ParseError::InvalidFormat)
+        );
+        assert_eq!(
+            parse("2023-11-5", "%Y-%m-%d"),
+            Err(ParseError::InvalidFormat)
+        );
+        assert_eq!(
+            parse("2023-11-05 ", "%Y-%m-%d"),
+            Err(ParseError::InvalidFormat)
+        );
+        assert_eq!(parse("2023-11", "%Y-%m"), Err(ParseError::InvalidFormat));
+        assert_eq!(
+            parse("2023-11-05", "%Y-%m-%Q"),
+            Err(ParseError::InvalidFormat)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn date_parse_from_format_legacy_layouts() {
+        let expected = Date::from_ymd(2023, 11, 5).unwrap();
+        for (text, pattern) in [
+            ("05/11/2023", "%d/%m/%Y"),
+            ("11-05-2023", "%m-%d-%Y"),
+            ("2023.11.05", "%Y.%m.%d"),
+            ("05.11.23", "%d.%m.%y"),
+            ("20231105", "%Y%m%d"),
+            ("11052023", "%m%d%Y"),
+        ] {
+            assert_eq!(Date::parse_from_format(text, pattern), Ok(expected));
+            assert_eq!(expected.format(pattern).unwrap(), text);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn datetime_format_literals_and_percent() {
+        let dt: DateTime = "2023-11-05T07:08:09.000000042Z".parse().unwrap();
+        assert_eq!(dt.format("%Y%% done").unwrap(), "2023% done");
+        assert_eq!(dt.format("{%Y} {{%m}}").unwrap(), "{2023} {{11}}");
+        assert_eq!(dt.format("").unwrap(), "");
+        assert_eq!(dt.format("no specifiers").unwrap(), "no specifiers");
+        assert_eq!(dt.format("%%%%").unwrap(), "%%");
+        assert_eq!(
+            dt.format("100%").unwrap_err(),
+            fasttime::FormatError::TrailingPercent
+        );
+        assert_eq!(
+            dt.format("%").unwrap_err(),
+            fasttime::FormatError::TrailingPercent
+        );
+        assert_eq!(
+            dt.format("%Q").unwrap_err(),
+            fasttime::FormatError::UnknownSpecifier('Q')
+        );
+        assert_eq!(
+            dt.format("%é").unwrap_err(),
+            fasttime::FormatError::UnknownSpecifier('é')
+        );
+        assert_eq!(dt.format("é %Y ü").unwrap(), "é 2023 ü");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn datetime_format_specifiers() {
+        let dt: DateTime = "2023-11-05T07:08:09.000000042Z".parse().unwrap();
+        assert_eq!(
+            dt.format("%Y-%m-%dT%H:%M:%S.%fZ").unwrap(),
+            "2023-11-05T07:08:09.000000042Z"
+        );
+        assert_eq!(dt.format("%F %T").unwrap(), "2023-11-05 07:08:09");
+        assert_eq!(dt.format("%y/%j/%u").unwrap(), "23/309/7");
+
+        let ancient: DateTime = "0005-01-01T00:00:00Z".parse().unwrap();
+        assert_eq!(ancient.format("%Y %y").unwrap(), "0005 05");
+
+        let mut buf = String::new();
+        dt.format_into("[%H]", &mut buf).unwrap();
+        assert_eq!(buf, "[07]");
+    }
+
+    #[test]
+    fn date_apply_duration_splits_days_and_remainder() {
+        let date = Date::from_ymd(2024, 2, 28).unwrap();
+
+        let (next, rest) = date.apply_duration(Duration::seconds(25 * 3600)).unwrap();
+        assert_eq!(next, Date::from_ymd(2024, 2, 29).unwrap());
+        assert_eq!(rest, Duration::seconds(3600));
+
+        let (prev, rest) = date.apply_duration(Duration::seconds(-3600)).unwrap();
+        assert_eq!(prev, Date::from_ymd(2024, 2, 27).unwrap());
+        assert_eq!(rest, Duration::seconds(23 * 3600));
+
+        let (same, rest) = date.apply_duration(Duration::seconds(-2 * 86_400)).unwrap();
+        assert_eq!(same, Date::from_ymd(2024, 2, 26).unwrap());
+        assert_eq!(rest, Duration::ZERO);
+
+        assert_eq!(
+            Date::MAX.apply_duration(Duration::seconds(86_400)),
+            Err(DateError::OutOfRange)
+        );
+        assert_eq!(
+            date.apply_duration(Duration::MIN),
+            Err(DateError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn duration_days_and_weeks() {
+        assert_eq!(Duration::days(2), Duration::seconds(2 * 86_400));
+        assert_eq!(Duration::weeks(-1), Duration::seconds(-7 * 86_400));
+        assert_eq!(Duration::checked_days(3), Some(Duration::days(3)));
+        assert_eq!(Duration::checked_weeks(3), Some(Duration::days(21)));
+
+        // i128 nanoseconds leave plenty of headroom above i64 weeks.
+        let max_weeks = Duration::checked_weeks(i64::MAX as i128).unwrap();
+        assert_eq!(max_weeks, Duration::weeks(i64::MAX));
+        assert_eq!(
+            max_weeks.total_nanos(),
+            i64::MAX as i128 * 604_800_000_000_000
+        );
+        assert_eq!(
+            Duration::checked_days(i64::MIN as i128),
+            Some(Duration::days(i64::MIN))
+        );
+
+        // Wider counts can overflow.
+        let max_days = i128::MAX / 86_400_000_000_000;
+        assert_eq!(
+            Duration::checked_days(max_days).map(|d| d.total_nanos()),
+            Some(max_days * 86_400_000_000_000)
+        );
+        assert_eq!(Duration::checked_days(max_days + 1), None);
+        assert_eq!(Duration::checked_days(-max_days - 1), None);
+        assert_eq!(Duration::checked_weeks(max_days / 7 + 1), None);
+        assert_eq!(Duration::checked_weeks(i128::MIN), None);
+    }
+
+    #[test]
+    fn datetime_alternate_display_omits_z() {
+        let dt: DateTime = "2023-11-05T23:59:59Z".parse().unwrap();
+        assert_eq!(format!("{}", dt), "2023-11-05T23:59:59Z");
+        assert_eq!(format!("{:#}", dt), "2023-11-05T23:59:59");
+
+        let frac: DateTime = "2023-11-05T23:59:59.25Z".parse().unwrap();
+        assert_eq!(format!("{:#}", frac), "2023-11-05T23:59:59.25");
+        assert_eq!(format!("{:?}", frac), "DateTime(2023-11-05T23:59:59.25Z)");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn now_utc_high_res_is_plausible() {
+        let before = DateTime::now_utc().unwrap();
+        let now = DateTime::now_utc_high_res().unwrap();
+        let after = DateTime::now_utc().unwrap();
+        assert!(before <= now && now <= after);
+        assert!(now.date.year >= 2024);
+        assert!(now.time.nanosecond < 1_000_000_000);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn datetime_elapsed() {
+        let past = DateTime::now_utc()
+            .unwrap()
+            .add_duration(Duration::seconds(-60))
+            .unwrap();
+        let elapsed = past.elapsed().unwrap();
+        assert!(elapsed >= Duration::seconds(60));
+        assert!(elapsed < Duration::seconds(3600));
+
+        let future = DateTime::now_utc()
+            .unwrap()
+            .add_duration(Duration::seconds(3600))
+            .unwrap();
+        assert!(future.elapsed().unwrap() < Duration::ZERO);
+    }
+
+    #[test]
+    fn datetime_unix_millis_and_micros() {
+        let dt = DateTime::from_unix_timestamp(1_700_000_000, 123_456_789).unwrap();
+        assert_eq!(dt.unix_timestamp_millis(), Ok(1_700_000_000_123));
+        assert_eq!(dt.unix_timestamp_micros(), Ok(1_700_000_000_123_456));
+        assert_eq!(
+            DateTime::from_unix_timestamp_millis(1_700_000_000_123),
+            DateTime::from_unix_timestamp(1_700_000_000, 123_000_000)
+        );
+        assert_eq!(
+            DateTime::from_unix_timestamp_micros(1_700_000_000_123_456),
+            DateTime::from_unix_timestamp(1_700_000_000, 123_456_000)
+        );
+
+        // Pre-epoch values round toward negative infinity.
+        let before = DateTime::from_unix_timestamp(-1, 999_999_999).unwrap();
+        assert_eq!(before.unix_timestamp_millis(), Ok(-1));
+        assert_eq!(before.unix_timestamp_micros(), Ok(-1));
+        let ms = DateTime::from_unix_timestamp_millis(-1).unwrap();
+        assert_eq!(ms.to_string(), "1969-12-31T23:59:59.999Z");
+        assert_eq!(ms.unix_timestamp_millis(), Ok(-1));
+        let us = DateTime::from_unix_timestamp_micros(-1_500_001).unwrap();
+        assert_eq!(us.to_string(), "1969-12-31T23:59:58.499999Z");
+
+        // i64 milliseconds cover about ±292 million years; Date does not.
+        let far = DateTime::new(Date::MAX, Time::from_hms_nano(0, 0, 0, 0).unwrap());
+        assert_eq!(far.unix_timestamp_millis(), Err(DateError::OutOfRange));
+        assert_eq!(far.unix_timestamp_micros(), Err(DateError::OutOfRange));
+        assert!(DateTime::from_unix_timestamp_millis(i64::MAX).is_ok());
+        assert!(DateTime::from_unix_timestamp_micros(i64::MIN).is_ok());
+    }
+
+    #[test]
+    fn duration_parse_humantime() {
+        let h = Duration::parse_humantime;
+        assert_eq!(h("1h30m"), Ok(Duration::seconds(5400)));
+        assert_eq!(h("250ms"), Ok(Duration::milliseconds(250)));
+        assert_eq!(h("-2.5s"), Ok(Duration::milliseconds(-2500)));
+        assert_eq!(h("100us"), Ok(Duration::microseconds(100)));
+        assert_eq!(h("100µs"), Ok(Duration::microseconds(100)));
+        assert_eq!(h("100μs"), Ok(Duration::microseconds(100)));
+        assert_eq!(h("5ns"), Ok(Duration::nanoseconds(5)));
+        assert_eq!(h("1.5h"), Ok(Duration::seconds(5400)));
+        assert_eq!(h("2d12h"), Ok(Duration::seconds(60 * 3600)));
+        assert_eq!(h("0"), Ok(Duration::ZERO));
+
+        assert_eq!(h(""), Err(DurationParseError::Empty));
+        assert_eq!(h("-"), Err(DurationParseError::Empty));
+        assert_eq!(h("5"), Err(DurationParseError::InvalidUnit));
+        assert_eq!(h("5y"), Err(DurationParseError::InvalidUnit));
+        assert_eq!(h("h"), Err(DurationParseError::InvalidNumber));
+        assert_eq!(h("1.h"), Err(DurationParseError::InvalidNumber));
+        assert_eq!(
+            h("99999999999999999999999999999999999999999h"),
+            Err(DurationParseError::Overflow)
+        );
+        assert_eq!(
+            h("1000000000000000000000000000000d"),
+            Err(DurationParseError::Overflow)
+        );
+
+        // The strict `FromStr` form doesn't take the extra units.
+        assert_eq!(
+            "2d".parse::<Duration>(),
+            Err(DurationParseError::InvalidUnit)
+        );
+        assert_eq!(
+            "1µs".parse::<Duration>(),
+            Err(DurationParseError::InvalidUnit)
+        );
+    }
+
+    #[test]
+    fn duration_mean_and_median() {
+        let ms = Duration::milliseconds;
+
+        let mut odd = [ms(30), ms(10), ms(20)];
+        assert_eq!(Duration::mean(&odd), Some(ms(20)));
+        assert_eq!(Duration::median(&mut odd), Some(ms(20)));
+        assert_eq!(odd, [ms(10), ms(20), ms(30)]);
+
+        let mut even = [ms(40), ms(10), ms(30), ms(20)];
+        assert_eq!(Duration::mean(&even), Some(Duration::microseconds(25_000)));
+        assert_eq!(
+            Duration::median(&mut even),
+            Some(Duration::microseconds(25_000))
+        );
+
+        let mut skewed = [ms(1), ms(2), ms(1_000)];
+        assert_eq!(
+            Duration::mean(&skewed),
+            Some(Duration::nanoseconds(334_333_333))
+        );
+        assert_eq!(Duration::median(&mut skewed), Some(ms(2)));
+
+        let mut empty: [Duration; 0] = [];
+        assert_eq!(Duration::mean(&empty), None);
+        assert_eq!(Duration::median(&mut empty), None);
+
+        // Truncation toward zero, and no overflow in the median midpoint.
+        let mut odd_ns = [Duration::nanoseconds(-1), Duration::nanoseconds(-2)];
+        assert_eq!(Duration::mean(&odd_ns), Some(Duration::nanoseconds(-1)));
+        assert_eq!(
+            Duration::median(&mut odd_ns),
+            Some(Duration::nanoseconds(-1))
+        );
+        let mut extremes = [Duration::MAX, Duration::MAX];
+        assert_eq!(Duration::mean(&extremes), None);
+        assert_eq!(Duration::median(&mut extremes), Some(Duration::MAX));
+        let mut spread = [Duration::MIN, Duration::MAX];
+        assert_eq!(Duration::median(&mut spread), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn readable_debug_output() {
+        let d = Duration::seconds(5400);
+        assert_eq!(format!("{:?}", d), "Duration(1h30m)");
+        assert_eq!(
+            format!("{:?}", -Duration::nanoseconds(1_500)),
+            "Duration(-1.5us)"
+        );
+        assert_eq!(format!("{:?}", Duration::ZERO), "Duration(0s)");
+
+        let dt: DateTime = "2024-01-01T00:00:00.5Z".parse().unwrap();
+        assert_eq!(format!("{:?}", dt), "DateTime(2024-01-01T00:00:00.5Z)");
+        assert_eq!(
+            format!("{:?}", Some(dt)),
+            "Some(DateTime(2024-01-01T00:00:00.5Z))"
+        );
+
+        let odt: OffsetDateTime = "2024-01-01T02:00:00+02:00".parse().unwrap();
+        assert_eq!(
+            format!("{:?}", odt),
+            "OffsetDateTime(2024-01-01T02:00:00+02:00)"
+        );
+        let overflow = OffsetDateTime::from_utc(
+            DateTime::new(Date::MAX, Time::from_hms_nano(23, 0, 0, 0).unwrap()),
+            UtcOffset::from_hours_minutes(true, 2, 0).unwrap(),
+        );
+        assert_eq!(
+            format!("{:?}", overflow),
+            "OffsetDateTime(utc=2147483647-12-31T23:00:00Z, offset=+02:00)"
+        );
+    }
+
+    #[test]
+    fn duration_add_sub() {
+        let date = Date::from_ymd(2020, 1, 1).unwrap();
+        let time = Time::from_hms_nano(0, 0, 0, 0).unwrap();
+        let dt = DateTime::new(date, time);
+        let dur = Duration::seconds(86_400); // 1 day
+        let dt2 = dt.add_duration(dur).unwrap();
+        assert_eq!(dt2.date.to_string(), "2020-01-02");
+        assert_eq!(dt2.time.to_string(), "00:00:00");
+
+        let diff = dt2.difference(dt);
+        assert_eq!(diff, dur);
+    }
+
+    #[test]
+    fn duration_compares_with_core_duration() {
+        use core::time::Duration as CoreDuration;
+
+        let half = Duration::milliseconds(500);
+        assert_eq!(half, CoreDuration::from_millis(500));
+        assert_eq!(CoreDuration::from_millis(500), half);
+        assert!(Duration::seconds(1) > CoreDuration::from_millis(500));
+        assert!(half < CoreDuration::from_nanos(500_000_001));
+        assert!(CoreDuration::from_secs(1) > half);
+        assert!(CoreDuration::from_millis(499) < half);
+
+        // Negative durations are below every `core` duration, including zero.
+        assert!(Duration::nanoseconds(-1) < CoreDuration::ZERO);
+        assert!(CoreDuration::ZERO > Duration::nanoseconds(-1));
+        assert_ne!(Duration::nanoseconds(-1), CoreDuration::from_nanos(1));
+        assert_eq!(Duration::ZERO, CoreDuration::ZERO);
+
+        assert!(Duration::MAX > CoreDuration::MAX);
+        assert!(Duration::MIN < CoreDuration::ZERO);
+    }
+
+    #[test]
+    fn duration_display_human_and_iso() {
+        let d = Duration::seconds(5415) + Duration::milliseconds(500);
+        assert_eq!(format!("{}", d), "1h30m15.5s");
+        assert_eq!(format!("{:#}", d), "PT1H30M15.5S");
+
+        let neg = Duration::milliseconds(-250);
+        assert_eq!(format!("{}", neg), "-250ms");
+        assert_eq!(format!("{:#}", neg), "-PT0.25S");
+
+        assert_eq!(format!("{}", Duration::ZERO), "0s");
+        assert_eq!(format!("{:#}", Duration::ZERO), "PT0S");
+
+        assert_eq!(format!("{}", Duration::seconds(3600)), "1h");
+        assert_eq!(format!("{:#}", Duration::seconds(3600)), "PT1H");
+        assert_eq!(format!("{}", Duration::seconds(90_000)), "25h");
+        assert_eq!(format!("{}", Duration::nanoseconds(1_500)), "1.5us");
+        assert_eq!(format!("{}", Duration::nanoseconds(7)), "7ns");
+        assert_eq!(format!("{:#}", Duration::nanoseconds(7)), "PT0.000000007S");
+    }
+
+    #[test]
+    fn duration_parse_human_form() {
+        let d: Duration = "1h30m15.5s".parse().unwrap();
+        assert_eq!(d, Duration::seconds(5415) + Duration::milliseconds(500));
+        assert_eq!("-250ms".parse(), Ok(Duration::milliseconds(-250)));
+        assert_eq!("0s".parse(), Ok(Duration::ZERO));
+        assert_eq!("0".parse(), Ok(Duration::ZERO));
+        assert_eq!("1.5us".parse(), Ok(Duration::nanoseconds(1_500)));
+        assert_eq!(Duration::try_from("2m"), Ok(Duration::seconds(120)));
+
+        assert_eq!("".parse::<Duration>(), Err(DurationParseError::Empty));
+        assert_eq!("-".parse::<Duration>(), Err(DurationParseError::Empty));
+        assert_eq!(
+            "5".parse::<Duration>(),
+            Err(DurationParseError::InvalidUnit)
+        );
+        assert_eq!(
+            "5x".parse::<Duration>(),
+            Err(DurationParseError::InvalidUnit)
+        );
+        assert_eq!(
+            "h".parse::<Duration>(),
+            Err(DurationParseError::InvalidNumber)
+        );
+        assert_eq!(
+            "1.s".parse::<Duration>(),
+            Err(DurationParseError::InvalidNumber)
+        );
+        assert_eq!(
+            "999999999999999999999999999999h".parse::<Duration>(),
+            Err(DurationParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn duration_parse_iso8601() {
+        let iso = Duration::parse_iso8601;
+        assert_eq!(iso("PT1H30M"), Ok(Duration::seconds(5400)));
+        assert_eq!(iso("PT1H30M15.5S"), "1h30m15.5s".parse());
+        assert_eq!(iso("-PT0.25S"), Ok(Duration::milliseconds(-250)));
+        assert_eq!(iso("PT0S"), Ok(Duration::ZERO));
+        assert_eq!(iso("P2DT3H"), Ok(Duration::seconds(2 * 86_400 + 3 * 3600)));
+        assert_eq!(iso("P1W"), Ok(Duration::seconds(7 * 86_400)));
+        assert_eq!(iso("PT1.5H"), Ok(Duration::seconds(5400)));
+        assert_eq!(iso("PT0.000000007S"), Ok(Duration::nanoseconds(7)));
+
+        assert_eq!(iso(""), Err(DurationParseError::Empty));
+        assert_eq!(iso("1H"), Err(DurationParseError::InvalidUnit));
+        assert_eq!(iso("P"), Err(DurationParseError::InvalidNumber));
+        assert_eq!(iso("PT"), Err(DurationParseError::InvalidUnit));
+        assert_eq!(iso("P1Y"), Err(DurationParseError::InvalidUnit));
+        assert_eq!(iso("P1M"), Err(DurationParseError::InvalidUnit));
+        assert_eq!(iso("PT1S1M"), Err(DurationParseError::InvalidUnit));
+        assert_eq!(iso("PT1H1H"), Err(DurationParseError::InvalidUnit));
+        assert_eq!(iso("PT1.5H2M"), Err(DurationParseError::InvalidNumber));
+        assert_eq!(iso("PT5"), Err(DurationParseError::InvalidUnit));
+
+        for d in [
+            Duration::seconds(5415) + Duration::milliseconds(500),
+            Duration::seconds(-90_061),
+            Duration::nanoseconds(1),
+            Duration::ZERO,
+        ] {
+            assert_eq!(iso(&format!("{:#}", d)), Ok(d));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn duration_iso8601_serde_field() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Job {
+            #[serde(with = "fasttime::serde::duration_iso8601")]
+            timeout: Duration,
+        }
+
+        let job: Job = serde_json::from_str(r#"{"timeout":"PT1H30M"}"#).unwrap();
+        assert_eq!(job.timeout, Duration::seconds(5400));
+        assert_eq!(
+            serde_json::to_string(&job).unwrap(),
+            r#"{"timeout":"PT1H30M"}"#
+        );
+        assert!(serde_json::from_str::<Job>(r#"{"timeout":"1h30m"}"#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn offset_datetime_rfc3339_serde_keeps_unknown_local() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Event {
+            #[serde(with = "fasttime::serde::rfc3339")]
+            at: OffsetDateTime,
+        }
+
+        let json = r#"{"at":"2023-11-05T23:59:59-00:00"}"#;
+        let event: Event = serde_json::from_str(json).unwrap();
+        assert!(event.at.offset.is_unknown_local());
+        assert_eq!(event.at.to_string(), "2023-11-05T23:59:59-00:00");
+        let out = serde_json::to_string(&event).unwrap();
+        assert_eq!(out, json);
+        let back: Event = serde_json::from_str(&out).unwrap();
+        assert_eq!(back, event);
+        assert!(back.at.offset.is_unknown_local());
+
+        // A known zero offset is written as `Z`, whichever way it was spelled.
+        for input in ["2023-11-05T23:59:59+00:00", "2023-11-05T23:59:59Z"] {
+            let json = format!(r#"{{"at":"{input}"}}"#);
+            let event: Event = serde_json::from_str(&json).unwrap();
+            assert!(!event.at.offset.is_unknown_local());
+            assert_eq!(
+                serde_json::to_string(&event).unwrap(),
+                r#"{"at":"2023-11-05T23:59:59Z"}"#
+            );
+        }
+
+        let event: Event = serde_json::from_str(r#"{"at":"2023-11-05T23:59:59.5+05:30"}"#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"at":"2023-11-05T23:59:59.5+05:30"}"#
+        );
+        assert!(serde_json::from_str::<Event>(r#"{"at":"2023-11-05"}"#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_human_readable_uses_iso_strings() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Row {
+            day: Date,
+            at: Time,
+            ts: DateTime,
+        }
+
+        let json = r#"{"day":"2023-11-05","at":"23:59:59.5","ts":"1969-12-31T23:59:59.25Z"}"#;
+        let row: Row = serde_json::from_str(json).unwrap();
+        assert_eq!(row.day, Date::from_ymd(2023, 11, 5).unwrap());
+        assert_eq!(
+            row.at,
+            Time::from_hms_nano(23, 59, 59, 500_000_000).unwrap()
+        );
+        assert_eq!(
+            row.ts,
+            DateTime::from_unix_timestamp(-1, 250_000_000).unwrap()
+        );
+        assert_eq!(serde_json::to_string(&row).unwrap(), json);
+
+        assert!(serde_json::from_str::<Date>(r#""2023-02-30""#).is_err());
+        assert!(serde_json::from_str::<Time>(r#""24:00:00""#).is_err());
+        assert!(serde_json::from_str::<DateTime>(r#""2023-11-05T23:59:59+02:00""#).is_err());
+        assert!(serde_json::from_str::<Date>("19666").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_compact_uses_numeric_layout() {
+        let date = Date::from_ymd(2023, 11, 5).unwrap();
+        let time = Time::from_hms_nano(23, 59, 59, 500_000_000).unwrap();
+        let dt = DateTime::from_unix_timestamp(-1, 250_000_000).unwrap();
+
+        // Same bytes as the documented primitives.
+        assert_eq!(
+            postcard::to_allocvec(&date).unwrap(),
+            postcard::to_allocvec(&date.days_since_unix_epoch()).unwrap()
+        );
+        assert_eq!(
+            postcard::to_allocvec(&time).unwrap(),
+            postcard::to_allocvec(&time.nanos_since_midnight()).unwrap()
+        );
+        assert_eq!(
+            postcard::to_allocvec(&dt).unwrap(),
+            postcard::to_allocvec(&(-1i64, 250_000_000u32)).unwrap()
+        );
+
+        for dt in [dt, DateTime::MIN, DateTime::MAX] {
+            let bytes = postcard::to_allocvec(&dt).unwrap();
+            assert!(bytes.len() <= 15, "{} bytes", bytes.len());
+            assert_eq!(postcard::from_bytes::<DateTime>(&bytes).unwrap(), dt);
+            let bytes = postcard::to_allocvec(&dt.date).unwrap();
+            assert_eq!(postcard::from_bytes::<Date>(&bytes).unwrap(), dt.date);
+            let bytes = postcard::to_allocvec(&dt.time).unwrap();
+            assert_eq!(postcard::from_bytes::<Time>(&bytes).unwrap(), dt.time);
+        }
+
+        let bad_nanos = postcard::to_allocvec(&(0i64, 1_000_000_000u32)).unwrap();
+        assert!(postcard::from_bytes::<DateTime>(&bad_nanos).is_err());
+        let bad_secs = postcard::to_allocvec(&(i64::MAX, 0u32)).unwrap();
+        assert!(postcard::from_bytes::<DateTime>(&bad_secs).is_err());
+        let bad_time = postcard::to_allocvec(&86_400_000_000_000u64).unwrap();
+        assert!(postcard::from_bytes::<Time>(&bad_time).is_err());
+        let bad_date = postcard::to_allocvec(&i64::MAX).unwrap();
+        assert!(postcard::from_bytes::<Date>(&bad_date).is_err());
+    }
+
+    #[test]
+    fn duration_display_parse_round_trip() {
+        let mut next = lcg(0x2545_f491_4f6c_dd1d);
+
+        let mut samples = vec![Duration::ZERO, Duration::MAX, Duration::MIN];
+        for _ in 0..2_000 {
+            let magnitude = (next() >> (next() % 64)) as i128;
+            let scaled = magnitude * [1, 1_000, 1_000_000_000][(next() % 3) as usize];
+            let sign = if next().is_multiple_of(2) { 1 } else { -1 };
+            samples.push(Duration::nanoseconds(sign * scaled));
+        }
+
+        for d in samples {
+            let text = d.to_string();
+            assert_eq!(text.parse::<Duration>(), Ok(d), "round trip of {text}");
+        }
+    }
+
+    #[test]
+    fn parsers_never_panic_on_unicode() {
+        // Mixes timestamp characters with multi-byte ones so that `+`, `-`,
+        // `T` and `Z` land next to them.
+        let mut rng = lcg(0x9e37_79b9_7f4a_7c15);
+        let mut next = || (rng() >> 33) as usize;
+        let alphabet = [
+            '0', '1', '2', '9', '-', '+', ':', '.', 'T', 'Z', 'z', ' ', 'é', 'ß', '€', '𝟘',
+            '\u{3000}', '\u{200b}', '－', '＋',
+        ];
+        let seeds = [
+            "2023-11-05T23:59:59.123456789Z",
+            "2023-11-05 23:59:59+02:00",
+            "20231105T235959Z",
+            "10:30:00-05:30",
+        ];
+
+        for i in 0..5_000 {
+            let mut chars: Vec<char> = if i % 2 == 0 {
+                seeds[next() % seeds.len()].chars().collect()
+            } else {
+                Vec::new()
+            };
+            for _ in 0..1 + next() % 6 {
+                let c = alphabet[next() % alphabet.len()];
+                chars.insert(next() % (chars.len() + 1), c);
+            }
+            let s: String = chars.into_iter().collect();
+
+            let rfc = DateTime::parse_rfc3339(&s);
+            let strict = s.parse::<DateTime>();
+            let basic = DateTime::parse_basic(&s);
+            let flexible = DateTime::parse_flexible(&s);
+            let sql = OffsetDateTime::from_sql_timestamp(&s, UtcOffset::UTC);
+            let _ = s.parse::<OffsetDateTime>();
+            let _ = s.parse::<Date>();
+            let _ = s.parse::<Time>();
+            let _ = s.parse::<Duration>();
+            let _ = Date::parse_named(&s);
+            let _ = Date::parse_basic(&s);
+            let _ = Time::parse_basic(&s);
+            let _ = Date::parse_from_format(&s, "%Y-%m-%d");
+            let _ = Time::parse_with_offset(&s);
+            let _ = parse_rfc3339_offset(&s);
+
+            if !s.is_ascii() {
+                assert_eq!(strict, Err(ParseError::NonAscii), "{s:?}");
+                assert_eq!(basic, Err(ParseError::NonAscii), "{s:?}");
+            }
+            if !s.trim().is_ascii() {
+                assert_eq!(rfc, Err(ParseError::NonAscii), "{s:?}");
+                assert_eq!(flexible, Err(ParseError::NonAscii), "{s:?}");
+                assert_eq!(sql, Err(ParseError::NonAscii), "{s:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn duration_checked_neg() {
+        assert_eq!(
+            Duration::seconds(5).checked_neg(),
+            Some(Duration::seconds(-5))
+        );
+        assert_eq!(Duration::ZERO.checked_neg(), Some(Duration::ZERO));
+        assert_eq!(
+            Duration::MAX.checked_neg(),
+            Some(Duration::nanoseconds(-i128::MAX))
+        );
+        assert_eq!(Duration::MIN.checked_neg(), None);
+    }
+
+    #[test]
+    fn duration_to_seconds_rounded() {
+        let d = Duration::nanoseconds(1_234_500_000);
+        assert_eq!(d.to_seconds_rounded(2), 1.23);
+        assert_eq!(d.to_seconds_rounded(0), 1.0);
+        assert_eq!(d.to_seconds_rounded(3), 1.235);
+        assert_eq!((-d).to_seconds_rounded(3), -1.235);
+        assert_eq!(d.to_seconds_rounded(9), 1.2345);
+        assert_eq!(d.to_seconds_rounded(200), 1.2345);
+        assert_eq!(Duration::milliseconds(500).to_seconds_rounded(0), 1.0);
+        assert_eq!(Duration::milliseconds(-500).to_seconds_rounded(0), -1.0);
+        assert_eq!(Duration::ZERO.to_seconds_rounded(4), 0.0);
+        assert!(Duration::MAX.to_seconds_rounded(0) > 0.0);
+        assert!(Duration::MIN.to_seconds_rounded(0) < 0.0);
+    }
+
+    #[test]
+    fn duration_from_float_seconds_and_millis() {
+        assert_eq!(
+            Duration::from_seconds_f64(1.5),
+            Ok(Duration::milliseconds(1_500))
+        );
+        assert_eq!(
+            Duration::from_seconds_f64(-0.25),
+            Ok(Duration::milliseconds(-250))
+        );
+        assert_eq!(Duration::from_seconds_f64(0.0), Ok(Duration::ZERO));
+        assert_eq!(Duration::from_seconds_f64(-0.0), Ok(Duration::ZERO));
+        assert_eq!(
+            Duration::from_seconds_f64(0.1),
+            Ok(Duration::milliseconds(100))
+        );
+        assert_eq!(
+            Duration::from_millis_f64(2.5),
+            Ok(Duration::microseconds(2_500))
+        );
+        assert_eq!(Duration::from_millis_f64(-1e3), Ok(Duration::seconds(-1)));
+
+        // Rounded to the nearest nanosecond, ties away from zero.
+        assert_eq!(
+            Duration::from_seconds_f64(1.4e-9),
+            Ok(Duration::nanoseconds(1))
+        );
+        assert_eq!(
+            Duration::from_millis_f64(0.0000005),
+            Ok(Duration::nanoseconds(1))
+        );
+        assert_eq!(
+            Duration::from_millis_f64(-0.0000005),
+            Ok(Duration::nanoseconds(-1))
+        );
+        assert_eq!(Duration::from_millis_f64(0.0000004), Ok(Duration::ZERO));
+
+        assert_eq!(
+            Duration::from_seconds_f64((1u64 << 62) as f64),
+            Ok(Duration::seconds(1 << 62))
+        );
+
+        for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                Duration::from_seconds_f64(bad),
+                Err(DurationError::NotFinite)
+            );
+            assert_eq!(
+                Duration::from_millis_f64(bad),
+                Err(DurationError::NotFinite)
+            );
+        }
+        for huge in [1.71e29, -1.71e29, f64::MAX, f64::MIN] {
+            assert_eq!(
+                Duration::from_seconds_f64(huge),
+                Err(DurationError::Overflow)
+            );
+        }
+        assert_eq!(
+            Duration::from_millis_f64(1.8e32),
+            Err(DurationError::Overflow)
+        );
+        assert!(Duration::from_seconds_f64(1.7e29).is_ok());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn duration_clock_string() {
+        assert_eq!(Duration::seconds(5_415).to_clock_string(), "01:30:15");
+        assert_eq!(Duration::seconds(-300).to_clock_string(), "-00:05:00");
+        assert_eq!(Duration::ZERO.to_clock_string(), "00:00:00");
+        assert_eq!(Duration::days(1).to_clock_string(), "24:00:00");
+        assert_eq!(Duration::seconds(360_000).to_clock_string(), "100:00:00");
+        assert_eq!(Duration::milliseconds(59_999).to_clock_string(), "00:00:59");
+        assert_eq!(Duration::milliseconds(-500).to_clock_string(), "00:00:00");
+
+        let d = Duration::seconds(5_415) + Duration::nanoseconds(123_456_789);
+        let mut out = String::new();
+        d.format_clock_into(&mut out, 3).unwrap();
+        assert_eq!(out, "01:30:15.123");
+        out.clear();
+        (-d).format_clock_into(&mut out, 12).unwrap();
+        assert_eq!(out, "-01:30:15.123456789");
+        out.clear();
+        Duration::milliseconds(-500)
+            .format_clock_into(&mut out, 1)
+            .unwrap();
+        assert_eq!(out, "-00:00:00.5");
+
+        assert!(Duration::MAX.to_clock_string().ends_with(":55"));
+        assert!(Duration::MIN.to_clock_string().starts_with('-'));
+    }
+
+    #[test]
+    fn duration_to_parts() {
+        let d = Duration::seconds(2 * 86_400 + 3 * 3600 + 4 * 60 + 5) + Duration::nanoseconds(6);
+        let parts = DurationParts {
+            negative: false,
+            days: 2,
+            hours: 3,
+            minutes: 4,
+            seconds: 5,
+            nanoseconds: 6,
+        };
+        assert_eq!(d.to_parts(), parts);
+        assert_eq!(
+            (-d).to_parts(),
+            DurationParts {
+                negative: true,
+                ..parts
+            }
+        );
+        assert_eq!(
+            Duration::ZERO.to_parts(),
+            DurationParts {
+                negative: false,
+                days: 0,
+                hours: 0,
+                minutes: 0,
+                seconds: 0,
+                nanoseconds: 0,
+            }
+        );
+
+        let min = Duration::MIN.to_parts();
+        assert!(min.negative);
+        assert_eq!(min.nanoseconds, 884_105_728);
+        assert_eq!(
+            min.days * 86_400_000_000_000
+                + 1_000_000_000
+                    * (min.hours as u128 * 3600 + min.minutes as u128 * 60 + min.seconds as u128)
+                + min.nanoseconds as u128,
+            i128::MIN.unsigned_abs()
+        );
+    }
+
+    #[test]
+    fn duration_bounds() {
+        assert_eq!(Duration::MAX.total_nanos(), i128::MAX);
+        assert_eq!(Duration::MIN.total_nanos(), i128::MIN);
+        assert!(Duration::MIN < Duration::ZERO && Duration::ZERO < Duration::MAX);
+        // Display must cope with the extremes (including `i128::MIN`).
+        assert!(Duration::MAX.to_string().ends_with('s'));
+        assert!(Duration::MIN.to_string().starts_with('-'));
+    }
+
+    #[test]
+    fn difference_keeps_nanosecond_precision() {
+        let date = Date::from_ymd(2023, 11, 5).unwrap();
+        let base = DateTime::new(date, Time::from_hms_nano(12, 0, 0, 0).unwrap());
+
+        let one_ns = DateTime::new(date, Time::from_hms_nano(12, 0, 0, 1).unwrap());
+        assert_eq!(one_ns.difference(base), Duration::nanoseconds(1));
+        assert_eq!(base.difference(one_ns), Duration::nanoseconds(-1));
+
+        let almost_sec = DateTime::new(date, Time::from_hms_nano(12, 0, 0, 999_999_999).unwrap());
+        assert_eq!(almost_sec.difference(base).total_nanos(), 999_999_999);
+
+        // Across midnight with differing sub-second parts.
+        let before = DateTime::new(date, Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap());
+        let after = DateTime::new(
+            Date::from_ymd(2023, 11, 6).unwrap(),
+            Time::from_hms_nano(0, 0, 0, 1).unwrap(),
+        );
+        assert_eq!(after.difference(before).total_nanos(), 2);
+        assert_eq!(before.difference(after).total_nanos(), -2);
+
+        let utc = UtcOffset::from_seconds(0).unwrap();
+        let plus_two = UtcOffset::from_hours_minutes(true, 2, 0).unwrap();
+        let a = OffsetDateTime::from_utc(after, plus_two);
+        let b = OffsetDateTime::from_utc(before, utc);
+        assert_eq!(a.difference(b).total_nanos(), 2);
+    }
+
+    #[test]
+    fn datetime_duration_since() {
+        let earlier = dt("2023-11-05T23:59:59.999999999Z");
+        let later = dt("2023-11-06T00:00:01Z");
+
+        assert_eq!(
+            later.duration_since(earlier),
+            Duration::nanoseconds(1_000_000_001)
+        );
+        assert_eq!(later.duration_since(earlier), later.difference(earlier));
+        assert_eq!(
+            earlier.duration_since(later),
+            Duration::nanoseconds(-1_000_000_001)
+        );
+
+        assert_eq!(
+            later.checked_duration_since(earlier),
+            Some(Duration::nanoseconds(1_000_000_001))
+        );
+        assert_eq!(later.checked_duration_since(later), Some(Duration::ZERO));
+        assert_eq!(earlier.checked_duration_since(later), None);
+        assert!(DateTime::MAX
+            .checked_duration_since(DateTime::MIN)
+            .is_some_and(|d| d > Duration::ZERO));
+        assert_eq!(DateTime::MIN.checked_duration_since(DateTime::MAX), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn datetime_to_rfc3339_opts() {
+        let whole = dt("2023-11-05T23:59:59Z");
+        assert_eq!(whole.to_rfc3339_opts(3), "2023-11-05T23:59:59.000Z");
+        assert_eq!(whole.to_rfc3339_opts(0), "2023-11-05T23:59:59Z");
+        let frac = dt("2023-11-05T23:59:59.123456789Z");
+        assert_eq!(frac.to_rfc3339_opts(0), "2023-11-05T23:59:59Z");
+        assert_eq!(frac.to_rfc3339_opts(3), "2023-11-05T23:59:59.123Z");
+        assert_eq!(frac.to_rfc3339_opts(6), "2023-11-05T23:59:59.123456Z");
+        assert_eq!(frac.to_rfc3339_opts(9), "2023-11-05T23:59:59.123456789Z");
+        assert_eq!(frac.to_rfc3339_opts(12), frac.to_rfc3339_opts(9));
+        assert_eq!(
+            dt(&frac.to_rfc3339_opts(6)),
+            dt("2023-11-05T23:59:59.123456Z")
+        );
+    }
+
+    #[test]
+    fn datetime_step_by() {
+        let start = dt("2024-01-01T00:00:00Z");
+        let end = dt("2024-01-02T00:00:00Z");
+        let quarter = Duration::seconds(15 * 60);
+
+        let buckets: Vec<_> = DateTime::step_by(start, end, quarter).collect();
+        assert_eq!(buckets.len(), 96);
+        assert_eq!(buckets[0], start);
+        assert_eq!(buckets[95], dt("2024-01-01T23:45:00Z"));
+
+        let uneven: Vec<_> =
+            DateTime::step_by(start, dt("2024-01-01T00:40:00Z"), quarter).collect();
+        assert_eq!(
+            uneven,
+            [
+                start,
+                dt("2024-01-01T00:15:00Z"),
+                dt("2024-01-01T00:30:00Z")
+            ]
+        );
+
+        let back: Vec<_> = DateTime::step_by(end, start, Duration::seconds(-8 * 3600)).collect();
+        assert_eq!(
+            back,
+            [end, dt("2024-01-01T16:00:00Z"), dt("2024-01-01T08:00:00Z")]
+        );
+
+        assert_eq!(DateTime::step_by(start, end, Duration::ZERO).count(), 0);
+        assert_eq!(DateTime::step_by(start, start, quarter).count(), 0);
+        assert_eq!(DateTime::step_by(end, start, quarter).count(), 0);
+        assert_eq!(DateTime::step_by(start, end, -quarter).count(), 0);
+
+        let near_max = DateTime::MAX.add_duration(Duration::seconds(-1)).unwrap();
+        let tail: Vec<_> =
+            DateTime::step_by(near_max, DateTime::MAX, Duration::seconds(1)).collect();
+        assert_eq!(tail, [near_max]);
+        assert_eq!(
+            DateTime::step_by(near_max, DateTime::MAX, Duration::seconds(2)).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn datetime_clamp() {
+        let min = dt("2024-01-01T00:00:00Z");
+        let max = dt("2024-01-01T23:59:59.999999999Z");
+        assert_eq!(dt("2023-12-31T23:59:59.999999999Z").clamp(min, max), min);
+        assert_eq!(dt("2024-01-02T00:00:00Z").clamp(min, max), max);
+        let inside = dt("2024-01-01T12:00:00.5Z");
+        assert_eq!(inside.clamp(min, max), inside);
+    }
+
+    #[test]
+    #[should_panic]
+    fn datetime_clamp_rejects_inverted_range() {
+        let _ = DateTime::MIN.clamp(DateTime::MAX, DateTime::MIN);
+    }
+
+    #[test]
+    fn datetime_eq_at_coarser_resolution() {
+        let a = dt("2023-11-05T12:00:00.123456789Z");
+
+        let b = dt("2023-11-05T12:00:00.123456Z");
+        assert_ne!(a, b);
+        assert!(a.eq_to_micros(b));
+        assert!(a.eq_to_millis(b));
+        assert!(a.eq_to_second(b));
+
+        let c = dt("2023-11-05T12:00:00.123999Z");
+        assert!(!a.eq_to_micros(c));
+        assert!(a.eq_to_millis(c));
+
+        let d = dt("2023-11-05T12:00:00.999Z");
+        assert!(!a.eq_to_millis(d));
+        assert!(a.eq_to_second(d));
+        assert!(!a.eq_to_second(dt("2023-11-05T12:00:01Z")));
+
+        // Truncation, not rounding, also before the epoch.
+        let e = dt("1969-12-31T23:59:59.9Z");
+        assert!(e.eq_to_second(dt("1969-12-31T23:59:59Z")));
+        assert!(!e.eq_to_second(dt("1970-01-01T00:00:00Z")));
+    }
+
+    #[test]
+    fn seconds_since_reference() {
+        let base: DateTime = "2023-11-05T12:00:00Z".parse().unwrap();
+
+        let later: DateTime = "2023-11-05T12:00:00.25Z".parse().unwrap();
+        assert_eq!(later.seconds_since(base), 0.25);
+        assert_eq!(base.seconds_since(later), -0.25);
+
+        let days: DateTime = "2023-11-08T18:30:00.5Z".parse().unwrap();
+        assert_eq!(
+            days.seconds_since(base),
+            3.0 * 86_400.0 + 6.5 * 3_600.0 + 0.5
+        );
+
+        let a: OffsetDateTime = "2023-11-08T20:30:00.5+02:00".parse().unwrap();
+        let b: OffsetDateTime = "2023-11-05T07:00:00-05:00".parse().unwrap();
+        assert_eq!(a.seconds_since(b), days.seconds_since(base));
     }
 
     #[test]
-    fn duration_add_sub() {
-        let date = Date::from_ymd(2020, 1, 1).unwrap();
-        let time = Time::from_hms_nano(0, 0, 0, 0).unwrap();
-        let dt = DateTime::new(date, time);
-        let dur = Duration::seconds(86_400); // 1 day
-        let dt2 = dt.add_duration(dur).unwrap();
-        assert_eq!(dt2.date.to_string(), "2020-01-02");
-        assert_eq!(dt2.time.to_string(), "00:00:00");
+    fn nearest_in_sorted_candidates() {
+        let slots = [
+            dt("2023-11-05T10:00:00Z"),
+            dt("2023-11-05T10:15:00Z"),
+            dt("2023-11-05T10:30:00Z"),
+        ];
 
-        let diff = dt2.difference(dt);
-        assert_eq!(diff, dur);
+        // Exactly between two slots: ties go to the earlier one.
+        assert_eq!(
+            dt("2023-11-05T10:07:30Z").nearest_in(&slots),
+            Some(slots[0])
+        );
+        assert_eq!(
+            dt("2023-11-05T10:07:30.000000001Z").nearest_in(&slots),
+            Some(slots[1])
+        );
+        assert_eq!(
+            dt("2023-11-05T10:15:00Z").nearest_in(&slots),
+            Some(slots[1])
+        );
+
+        // Outside the candidate range.
+        assert_eq!(
+            dt("2023-11-05T09:00:00Z").nearest_in(&slots),
+            Some(slots[0])
+        );
+        assert_eq!(
+            dt("2023-11-06T00:00:00Z").nearest_in(&slots),
+            Some(slots[2])
+        );
+
+        assert_eq!(dt("2023-11-05T10:00:00Z").nearest_in(&[]), None);
     }
 
     #[test]
@@ -77,6 +2335,131 @@ mod tests {
         assert_eq!(dt.to_string(), "2023-11-05T23:59:59.001Z");
     }
 
+    #[test]
+    fn utc_offset_whole_hours_and_minutes() {
+        assert!(UtcOffset::UTC.is_utc());
+        assert_eq!(UtcOffset::UTC, UtcOffset::from_seconds(0).unwrap());
+
+        let minus_five = UtcOffset::from_whole_hours(-5).unwrap();
+        assert_eq!(minus_five.as_seconds(), -5 * 3600);
+        assert_eq!(
+            minus_five,
+            UtcOffset::from_hours_minutes(false, 5, 0).unwrap()
+        );
+        assert!(UtcOffset::from_whole_hours(-12).is_ok());
+        assert!(UtcOffset::from_whole_hours(14).is_ok());
+        assert_eq!(
+            UtcOffset::from_whole_hours(-13),
+            Err(UtcOffsetError::OutOfRange)
+        );
+        assert_eq!(
+            UtcOffset::from_whole_hours(15),
+            Err(UtcOffsetError::OutOfRange)
+        );
+
+        assert!(minus_five.is_whole_hours());
+        assert!(minus_five.is_whole_minutes());
+        let india = UtcOffset::from_hours_minutes(true, 5, 30).unwrap();
+        assert!(!india.is_whole_hours());
+        assert!(india.is_whole_minutes());
+        let odd = UtcOffset::from_seconds(-(5 * 3600 + 30 * 60 + 15)).unwrap();
+        assert!(!odd.is_whole_hours());
+        assert!(!odd.is_whole_minutes());
+    }
+
+    #[test]
+    fn utc_offset_from_seconds_boundaries() {
+        assert_eq!(
+            UtcOffset::from_seconds(86_400).unwrap().as_seconds(),
+            86_400
+        );
+        assert_eq!(
+            UtcOffset::from_seconds(-86_400).unwrap().as_seconds(),
+            -86_400
+        );
+        assert_eq!(
+            UtcOffset::from_seconds(86_401),
+            Err(UtcOffsetError::OutOfRange)
+        );
+        assert_eq!(
+            UtcOffset::from_seconds(-86_401),
+            Err(UtcOffsetError::OutOfRange)
+        );
+        assert_eq!(
+            UtcOffset::from_seconds(86_400).unwrap().to_string(),
+            "+24:00"
+        );
+        assert_eq!(
+            UtcOffset::from_seconds(-86_400).unwrap().to_string(),
+            "-24:00"
+        );
+    }
+
+    #[test]
+    fn utc_offset_saturating_add_and_neg() {
+        let india = UtcOffset::from_hours_minutes(true, 5, 30).unwrap();
+        assert_eq!(
+            india.saturating_add(1_800),
+            UtcOffset::from_whole_hours(6).unwrap()
+        );
+        assert_eq!(india.saturating_add(-19_800), UtcOffset::UTC);
+        assert_eq!(india.saturating_add(0), india);
+
+        let max = UtcOffset::from_seconds(86_400).unwrap();
+        let min = UtcOffset::from_seconds(-86_400).unwrap();
+        assert_eq!(india.saturating_add(200_000), max);
+        assert_eq!(india.saturating_add(-200_000), min);
+        assert_eq!(max.saturating_add(i32::MAX), max);
+        assert_eq!(min.saturating_add(i32::MIN), min);
+        assert!(!UtcOffset::UNKNOWN_LOCAL
+            .saturating_add(0)
+            .is_unknown_local());
+
+        assert_eq!((-india).to_string(), "-05:30");
+        assert_eq!(-(-india), india);
+        assert_eq!(-max, min);
+        assert_eq!(-UtcOffset::UTC, UtcOffset::UTC);
+        assert_eq!((-UtcOffset::UNKNOWN_LOCAL).to_string(), "+00:00");
+    }
+
+    #[test]
+    fn utc_offset_from_seconds_unchecked_in_const() {
+        const IST: UtcOffset = UtcOffset::from_seconds_unchecked(19_800);
+        const TABLE: [UtcOffset; 2] = [
+            UtcOffset::from_seconds_unchecked(-18_000),
+            UtcOffset::from_seconds_unchecked(0),
+        ];
+        assert_eq!(IST, UtcOffset::from_hours_minutes(true, 5, 30).unwrap());
+        assert_eq!(IST.to_string(), "+05:30");
+        assert_eq!(TABLE[0], UtcOffset::from_whole_hours(-5).unwrap());
+        assert!(TABLE[1].is_utc() && !TABLE[1].is_unknown_local());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn utc_offset_from_seconds_unchecked_catches_out_of_range() {
+        let _ = UtcOffset::from_seconds_unchecked(86_401);
+    }
+
+    #[test]
+    fn datetime_assume_offset() {
+        let dt: DateTime = "2024-01-01T00:00:00Z".parse().unwrap();
+        let offset = UtcOffset::from_whole_hours(2).unwrap();
+
+        let odt = dt.assume_offset(offset);
+        assert_eq!(odt, OffsetDateTime::from_utc(dt, offset));
+        assert_eq!(odt.utc, dt);
+        assert_eq!(
+            odt.to_local().unwrap(),
+            "2024-01-01T02:00:00Z".parse::<DateTime>().unwrap()
+        );
+        assert_eq!(
+            dt.assume_utc(),
+            OffsetDateTime::from_utc(dt, UtcOffset::UTC)
+        );
+    }
+
     #[test]
     fn offset_datetime_rfc3339() {
         let s = "2023-11-05T23:59:59.5+02:00";
@@ -87,6 +2470,113 @@ mod tests {
         assert_eq!(odt_z.to_string(), "2023-11-05T23:59:59Z");
     }
 
+    #[test]
+    fn datetime_parse_rfc3339_normalizes_offset() {
+        let dt = DateTime::parse_rfc3339("2023-11-05T23:59:59+02:00").unwrap();
+        assert_eq!(dt.to_string(), "2023-11-05T21:59:59Z");
+
+        let dt = DateTime::parse_rfc3339("2023-11-05T23:59:59.25-05:30").unwrap();
+        assert_eq!(dt.to_string(), "2023-11-06T05:29:59.25Z");
+
+        let dt = DateTime::parse_rfc3339("2023-11-05T23:59:59Z").unwrap();
+        assert_eq!(dt, "2023-11-05T23:59:59Z".parse::<DateTime>().unwrap());
+
+        assert_eq!(
+            DateTime::parse_rfc3339("2023-11-05T23:59:59"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            DateTime::parse_rfc3339("2023-02-30T00:00:00Z"),
+            Err(ParseError::InvalidDate)
+        );
+
+        // The strict `FromStr` still only accepts `Z`.
+        assert!("2023-11-05T23:59:59+02:00".parse::<DateTime>().is_err());
+    }
+
+    #[test]
+    fn datetime_parse_flexible() {
+        for (input, expected) in [
+            ("2023-11-05T23:59:59Z", "2023-11-05T23:59:59Z"),
+            ("2023-11-05T23:59:59.25+02:00", "2023-11-05T21:59:59.25Z"),
+            ("2023-11-05T23:59:59", "2023-11-05T23:59:59Z"),
+            ("2023-11-05 23:59:59", "2023-11-05T23:59:59Z"),
+            ("2023-11-05 23:59:59.5-0530", "2023-11-06T05:29:59.5Z"),
+            ("  2023-11-05T23:59:59Z\n", "2023-11-05T23:59:59Z"),
+            ("2023-11-05", "2023-11-05T00:00:00Z"),
+            ("-0044-03-15", "-0044-03-15T00:00:00Z"),
+            ("10:30:00", "1970-01-01T10:30:00Z"),
+            ("10:30:00.125Z", "1970-01-01T10:30:00.125Z"),
+            ("01:00:00+02:00", "1969-12-31T23:00:00Z"),
+        ] {
+            assert_eq!(
+                DateTime::parse_flexible(input),
+                Ok(dt(expected)),
+                "{input:?}"
+            );
+        }
+
+        assert_eq!(
+            DateTime::parse_flexible("2023-02-30"),
+            Err(ParseError::InvalidDate)
+        );
+        assert_eq!(
+            DateTime::parse_flexible("2023-02-30 10:00:00"),
+            Err(ParseError::InvalidDate)
+        );
+        assert_eq!(
+            DateTime::parse_flexible("25:00:00"),
+            Err(ParseError::InvalidTime)
+        );
+        assert_eq!(
+            DateTime::parse_flexible("2023-11-05T10:00:00+99:00"),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(DateTime::parse_flexible(""), Err(ParseError::InvalidDate));
+        assert_eq!(
+            DateTime::parse_flexible("yesterday"),
+            Err(ParseError::InvalidDate)
+        );
+    }
+
+    #[test]
+    fn datetime_from_str_hints_at_offset_datetime() {
+        for s in [
+            "2023-11-05T23:59:59+02:00",
+            "2023-11-05T23:59:59.5-05:30",
+            "2023-11-05 23:59:59+0200",
+            "2023-11-05T23:59:59-00:00",
+        ] {
+            assert_eq!(
+                s.parse::<DateTime>(),
+                Err(ParseError::UseOffsetDateTime),
+                "{s:?}"
+            );
+            assert!(s.parse::<OffsetDateTime>().is_ok());
+        }
+
+        assert_eq!(
+            "2023-11-05T23:59:59".parse::<DateTime>(),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            "2023-11-05T23:59:59+99:00".parse::<DateTime>(),
+            Err(ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            "2023-11-05".parse::<DateTime>(),
+            Err(ParseError::InvalidFormat)
+        );
+        assert_eq!(
+            "2023-02-30T00:00:00Z".parse::<DateTime>(),
+            Err(ParseError::InvalidDate)
+        );
+        assert_eq!(
+            "2023-11-05T24:00:00Z".parse::<DateTime>(),
+            Err(ParseError::InvalidTime)
+        );
+    }
+
     #[test]
     fn date_weekday_and_ordinal() {
         let monday = Date::from_ymd(2023, 11, 6).unwrap();
@@ -98,6 +2588,59 @@ mod tests {
         assert_eq!(leap.ordinal(), 61);
     }
 
+    #[test]
+    fn date_with_ordinal() {
+        assert_eq!(date(2024, 7, 4).with_ordinal(60), Ok(date(2024, 2, 29)));
+        assert_eq!(date(2023, 7, 4).with_ordinal(60), Ok(date(2023, 3, 1)));
+        assert_eq!(date(2024, 7, 4).with_ordinal(366), Ok(date(2024, 12, 31)));
+        assert_eq!(
+            date(2023, 7, 4).with_ordinal(366),
+            Err(DateError::InvalidDate)
+        );
+        assert_eq!(
+            date(2023, 7, 4).with_ordinal(0),
+            Err(DateError::InvalidDate)
+        );
+        assert_eq!(Date::MAX.with_ordinal(1), Ok(date(i32::MAX, 1, 1)));
+
+        for year in [1900, 2000, 2023, 2024] {
+            let mut day = date(year, 1, 1);
+            while day.year == year {
+                assert_eq!(day.with_ordinal(day.ordinal()), Ok(day));
+                day = day.succ().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn date_num_days_from_monday_and_sunday() {
+        const ISO_NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+        let monday = Date::from_ymd(2023, 11, 6).unwrap();
+        assert_eq!(monday.num_days_from_monday(), 0);
+        assert_eq!(monday.num_days_from_sunday(), 1);
+        let sunday = Date::from_ymd(2023, 11, 5).unwrap();
+        assert_eq!(sunday.num_days_from_monday(), 6);
+        assert_eq!(sunday.num_days_from_sunday(), 0);
+        assert_eq!(ISO_NAMES[sunday.num_days_from_monday() as usize], "Sun");
+
+        // Agrees with `weekday()` on both sides of the epoch and at the ends
+        // of the range.
+        let mut dates = vec![Date::MIN, Date::MAX];
+        for days in -800..800 {
+            dates.push(Date::from_days_since_unix_epoch(days * 37).unwrap());
+        }
+        for date in dates {
+            let from_monday = date.num_days_from_monday();
+            assert_eq!(from_monday + 1, date.weekday().number_from_monday());
+            assert_eq!(date.num_days_from_sunday(), (from_monday + 1) % 7);
+            assert_eq!(
+                ISO_NAMES[from_monday as usize],
+                date.weekday().short_name(),
+                "{date}"
+            );
+        }
+    }
+
     #[test]
     fn time_fractional_and_nanos() {
         let t: Time = "12:34:56.123450700".parse().unwrap();
@@ -114,11 +2657,23 @@ mod tests {
             Err(TimeError::InvalidTime)
         ));
         assert!(matches!(
-            "12:00:00.1234567890".parse::<Time>(),
+            "12:00:00.1234567890x".parse::<Time>(),
             Err(TimeError::InvalidTime)
         ));
     }
 
+    #[test]
+    fn time_parse_truncates_long_fraction() {
+        let t: Time = "12:00:00.1234567891".parse().unwrap();
+        assert_eq!(t.nanosecond, 123_456_789);
+
+        let t: Time = "12:00:00.999999999999".parse().unwrap();
+        assert_eq!(t.nanosecond, 999_999_999);
+
+        let dt: DateTime = "2023-11-05T12:00:00.123456789012Z".parse().unwrap();
+        assert_eq!(dt.time.nanosecond, 123_456_789);
+    }
+
     #[test]
     fn offset_datetime_local_conversion_and_duration() {
         let date = Date::from_ymd(2021, 1, 2).unwrap();
@@ -136,6 +2691,291 @@ mod tests {
         assert_eq!(later.difference(odt), Duration::seconds(30));
     }
 
+    #[test]
+    fn offset_datetime_from_unix_timestamp() {
+        let offset = UtcOffset::from_hours_minutes(true, 5, 30).unwrap();
+        let odt = OffsetDateTime::from_unix_timestamp(1_699_228_799, 500, offset).unwrap();
+        assert_eq!(
+            odt.utc,
+            DateTime::from_unix_timestamp(1_699_228_799, 500).unwrap()
+        );
+        assert_eq!(odt.offset, offset);
+        assert_eq!(odt.to_string(), "2023-11-06T05:29:59.0000005+05:30");
+
+        let normalized = OffsetDateTime::from_unix_timestamp(0, -1, offset).unwrap();
+        assert_eq!(normalized.utc.to_string(), "1969-12-31T23:59:59.999999999Z");
+        assert_eq!(
+            OffsetDateTime::from_unix_timestamp(i64::MAX, 0, offset),
+            Err(DateError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn offset_datetime_is_same_local_date() {
+        let late = odt("2024-06-16T00:30:00+02:00");
+        let early = odt("2024-06-15T20:00:00-02:00");
+        assert_eq!(late.utc.date, early.utc.date);
+        assert!(!late.is_same_local_date(&early));
+        assert!(early.is_same_local_date(&odt("2024-06-15T08:00:00+09:00")));
+        assert!(late.is_same_local_date(&late));
+        assert_eq!(
+            late.is_same_local_date(&early),
+            late.local_date() == early.local_date()
+        );
+
+        let before_epoch = odt("1969-12-31T23:59:59-01:00");
+        assert!(before_epoch.is_same_local_date(&odt("1969-12-31T00:00:00Z")));
+        assert!(!before_epoch.is_same_local_date(&odt("1970-01-01T00:00:00Z")));
+
+        let offset = UtcOffset::from_hours_minutes(true, 2, 0).unwrap();
+        let past_max = OffsetDateTime::from_utc(DateTime::MAX, offset);
+        assert!(past_max.local_date().is_err());
+        assert!(past_max.is_same_local_date(&past_max));
+        assert!(
+            !past_max.is_same_local_date(&OffsetDateTime::from_utc(DateTime::MAX, UtcOffset::UTC))
+        );
+    }
+
+    #[test]
+    fn offset_datetime_display_falls_back_when_local_overflows() {
+        let utc = DateTime::new(Date::MAX, Time::from_hms_nano(23, 0, 0, 0).unwrap());
+        let offset = UtcOffset::from_hours_minutes(true, 2, 0).unwrap();
+        let odt = OffsetDateTime::from_utc(utc, offset);
+        assert!(odt.to_local().is_err());
+        assert_eq!(odt.to_string(), utc.to_string());
+    }
+
+    #[test]
+    fn offset_datetime_local_accessors() {
+        let odt: OffsetDateTime = "2023-12-31T23:30:15.25-02:00".parse().unwrap();
+        assert_eq!(odt.utc.date, Date::from_ymd(2024, 1, 1).unwrap());
+
+        assert_eq!(odt.local_date(), Ok(Date::from_ymd(2023, 12, 31).unwrap()));
+        assert_eq!(
+            odt.local_time(),
+            Ok(Time::from_hms_nano(23, 30, 15, 250_000_000).unwrap())
+        );
+        assert_eq!(odt.year(), Ok(2023));
+        assert_eq!(odt.month(), Ok(12));
+        assert_eq!(odt.day(), Ok(31));
+        assert_eq!(odt.hour(), Ok(23));
+        assert_eq!(odt.minute(), Ok(30));
+        assert_eq!(odt.second(), Ok(15));
+        assert_eq!(odt.nanosecond(), 250_000_000);
+        assert_eq!(odt.weekday(), Ok(Weekday::Sunday));
+
+        let overflow = OffsetDateTime::from_utc(
+            DateTime::new(Date::MAX, Time::from_hms_nano(23, 0, 0, 0).unwrap()),
+            UtcOffset::from_hours_minutes(true, 2, 0).unwrap(),
+        );
+        assert_eq!(overflow.year(), Err(DateError::OutOfRange));
+    }
+
+    #[test]
+    fn offset_datetime_never_observes_dst() {
+        for s in [
+            "2024-03-31T02:30:00+01:00",
+            "2024-10-27T02:30:00+02:00",
+            "2024-07-01T12:00:00-04:00",
+            "2024-01-01T00:00:00Z",
+        ] {
+            let odt: OffsetDateTime = s.parse().unwrap();
+            assert!(!odt.is_dst());
+            assert_eq!(odt.fold(), 0);
+        }
+    }
+
+    #[test]
+    fn offset_datetime_cmp_local() {
+        let berlin = odt("2024-06-15T09:00:00+02:00");
+        let utc = odt("2024-06-15T07:00:00Z");
+        let new_york = odt("2024-06-15T09:00:00-04:00");
+
+        assert_eq!(berlin.cmp(&utc), core::cmp::Ordering::Equal);
+        assert_eq!(berlin.cmp_local(&utc), Ok(core::cmp::Ordering::Greater));
+        assert_eq!(utc.cmp_local(&berlin), Ok(core::cmp::Ordering::Less));
+
+        assert_eq!(berlin.cmp(&new_york), core::cmp::Ordering::Less);
+        assert_eq!(berlin.cmp_local(&new_york), Ok(core::cmp::Ordering::Equal));
+
+        let overflow = OffsetDateTime::from_utc(
+            DateTime::new(Date::MAX, Time::from_hms_nano(23, 0, 0, 0).unwrap()),
+            UtcOffset::from_hours_minutes(true, 2, 0).unwrap(),
+        );
+        assert_eq!(overflow.cmp_local(&utc), Err(DateError::OutOfRange));
+
+        assert_eq!(berlin.local_key(), new_york.local_key());
+        assert!(berlin.local_key() > utc.local_key());
+        assert_eq!(
+            berlin.local_key(),
+            berlin.to_local().unwrap().unix_timestamp_nanos()
+        );
+        assert!(overflow.local_key() > berlin.local_key());
+
+        let mut events = [berlin, utc, odt("2024-06-15T08:00:00+05:00")];
+        events.sort_by_key(OffsetDateTime::local_key);
+        assert_eq!(events, [utc, odt("2024-06-15T08:00:00+05:00"), berlin]);
+    }
+
+    #[test]
+    fn datetime_hash_eq_and_ord_agree() {
+        use std::collections::HashSet;
+
+        // The same instant built three different ways.
+        let a = DateTime::from_unix_timestamp(1_700_000_000, 250_000_000).unwrap();
+        let b: DateTime = "2023-11-14T22:13:20.25Z".parse().unwrap();
+        let c = DateTime::new(
+            Date::from_ymd(2023, 11, 14).unwrap(),
+            Time::from_hms_nano(22, 13, 20, 250_000_000).unwrap(),
+        );
+        let later = a.add_duration(Duration::nanoseconds(1)).unwrap();
+
+        for (x, y) in [(a, b), (b, c), (a, c), (a, later), (later, c)] {
+            let equal = x == y;
+            assert_eq!(equal, x.cmp(&y) == core::cmp::Ordering::Equal);
+            if equal {
+                assert_eq!(hash_of(&x), hash_of(&y));
+            }
+        }
+
+        let set: HashSet<DateTime> = [a, b, c, later].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&b));
+        assert!(set.contains(&later));
+    }
+
+    #[test]
+    fn offset_datetime_parses_hour_only_offset() {
+        let odt: OffsetDateTime = "2023-11-05T23:59:59+07".parse().unwrap();
+        assert_eq!(odt.offset, UtcOffset::from_whole_hours(7).unwrap());
+        assert_eq!(odt.offset.to_string(), "+07:00");
+        assert_eq!(odt.utc.to_string(), "2023-11-05T16:59:59Z");
+        assert_eq!(odt.to_string(), "2023-11-05T23:59:59+07:00");
+        assert_eq!(odt.to_string().parse::<OffsetDateTime>(), Ok(odt));
+
+        let neg: OffsetDateTime = "2023-11-05T23:59:59.5-07".parse().unwrap();
+        assert_eq!(neg.offset, UtcOffset::from_whole_hours(-7).unwrap());
+        assert_eq!(neg.to_string(), "2023-11-05T23:59:59.5-07:00");
+
+        let compact: OffsetDateTime = "2023-11-05T23:59:59+0700".parse().unwrap();
+        assert_eq!(compact, odt);
+
+        assert!("2023-11-05T23:59:59+7".parse::<OffsetDateTime>().is_err());
+        assert!("2023-11-05T23:59:59+070".parse::<OffsetDateTime>().is_err());
+    }
+
+    #[test]
+    fn offset_negative_zero_is_unknown_local() {
+        let unknown = parse_rfc3339_offset("-00:00").unwrap();
+        assert!(unknown.is_unknown_local());
+        assert!(unknown.is_utc());
+        assert_eq!(unknown.to_string(), "-00:00");
+        assert_eq!(parse_rfc3339_offset("-00"), Ok(UtcOffset::UNKNOWN_LOCAL));
+        assert!(parse_rfc3339_offset("-0000").unwrap().is_unknown_local());
+
+        for s in ["Z", "z", "+00:00", "+00", "+0000"] {
+            let utc = parse_rfc3339_offset(s).unwrap();
+            assert!(!utc.is_unknown_local(), "{s}");
+            assert_eq!(utc.to_string(), "+00:00");
+        }
+        assert!(!UtcOffset::from_hours_minutes(false, 0, 0)
+            .unwrap()
+            .is_unknown_local());
+
+        // Same offset for comparison purposes.
+        assert_eq!(unknown, UtcOffset::UTC);
+        assert_eq!(unknown.cmp(&UtcOffset::UTC), core::cmp::Ordering::Equal);
+
+        let odt: OffsetDateTime = "2023-11-05T23:59:59-00:00".parse().unwrap();
+        assert!(odt.offset.is_unknown_local());
+        assert_eq!(odt.to_string(), "2023-11-05T23:59:59-00:00");
+        assert_eq!(
+            odt,
+            "2023-11-05T23:59:59Z".parse::<OffsetDateTime>().unwrap()
+        );
+        assert!(!odt.normalized_utc().offset.is_unknown_local());
+        assert_eq!(
+            "2023-11-05T23:59:59+00:00"
+                .parse::<OffsetDateTime>()
+                .unwrap()
+                .to_string(),
+            "2023-11-05T23:59:59Z"
+        );
+    }
+
+    #[test]
+    fn offset_datetime_from_sql_timestamp() {
+        let utc = UtcOffset::UTC;
+
+        let odt = OffsetDateTime::from_sql_timestamp("2023-11-05 23:59:59+02", utc).unwrap();
+        assert_eq!(odt.offset, UtcOffset::from_whole_hours(2).unwrap());
+        assert_eq!(odt.to_string(), "2023-11-05T23:59:59+02:00");
+
+        let odt = OffsetDateTime::from_sql_timestamp("2023-11-05 23:59:59.123456+00", utc).unwrap();
+        assert!(odt.offset.is_utc());
+        assert_eq!(odt.utc.time.nanosecond, 123_456_000);
+
+        let odt = OffsetDateTime::from_sql_timestamp("2023-11-05 23:59:59-05:30", utc).unwrap();
+        assert_eq!(odt.offset.as_seconds(), -(5 * 3600 + 30 * 60));
+
+        // No offset: the default is used.
+        let odt = OffsetDateTime::from_sql_timestamp("2023-11-05 23:59:59", utc).unwrap();
+        assert_eq!(odt.to_string(), "2023-11-05T23:59:59Z");
+        let plus_one = UtcOffset::from_whole_hours(1).unwrap();
+        let odt = OffsetDateTime::from_sql_timestamp("2023-11-05 23:59:59", plus_one).unwrap();
+        assert_eq!(odt.offset, plus_one);
+        assert_eq!(odt.utc.to_string(), "2023-11-05T22:59:59Z");
+
+        assert_eq!(
+            OffsetDateTime::from_sql_timestamp("2023-11-05", utc),
+            Err(ParseError::InvalidFormat)
+        );
+        assert_eq!(
+            OffsetDateTime::from_sql_timestamp("2023-11-05 25:00:00", utc),
+            Err(ParseError::InvalidTime)
+        );
+        assert_eq!(
+            OffsetDateTime::from_sql_timestamp("2023-11-05 23:59:59+2", utc),
+            Err(ParseError::InvalidOffset)
+        );
+
+        // `FromStr` still requires an explicit offset.
+        assert!("2023-11-05 23:59:59".parse::<OffsetDateTime>().is_err());
+        assert!("2023-11-05 23:59:59+02".parse::<OffsetDateTime>().is_ok());
+    }
+
+    #[test]
+    fn offset_datetime_normalized_utc() {
+        let a: OffsetDateTime = "2023-11-05T23:59:59+02:00".parse().unwrap();
+        let b: OffsetDateTime = "2023-11-05T16:29:59-05:30".parse().unwrap();
+        assert_ne!(a, b);
+
+        let (na, nb) = (a.normalized_utc(), b.normalized_utc());
+        assert_eq!(na, nb);
+        assert_eq!(hash_of(&na), hash_of(&nb));
+        assert!(na.offset.is_utc());
+        assert_eq!(na.utc, a.utc);
+        assert_eq!(na.to_string(), "2023-11-05T21:59:59Z");
+    }
+
+    #[test]
+    fn offset_datetime_compares_with_datetime_by_instant() {
+        let utc: DateTime = "2023-11-05T21:59:59Z".parse().unwrap();
+        let odt: OffsetDateTime = "2023-11-05T23:59:59+02:00".parse().unwrap();
+
+        assert!(odt.same_instant(utc));
+        assert_eq!(odt, utc);
+        assert_eq!(utc, odt);
+        assert_eq!(odt.cmp_instant(utc), core::cmp::Ordering::Equal);
+
+        let later = utc.add_duration(Duration::nanoseconds(1)).unwrap();
+        assert!(!odt.same_instant(later));
+        assert_ne!(odt, later);
+        assert!(odt < later);
+        assert!(later > odt);
+        assert_eq!(odt.cmp_instant(later), core::cmp::Ordering::Less);
+    }
+
     /// Test 1: The "Civil" Limits (i32 boundaries)
     /// The algorithm theoretically supports ~1.3 trillion years, but the struct
     /// enforces i32. We verify the code handles these explicit boundaries correctly.
@@ -275,6 +3115,47 @@ mod tests {
         );
     }
 
+    /// ISO week dates must round-trip for every day of a 400-year cycle,
+    /// and agree with an independently stepped week count.
+    #[test]
+    fn test_iso_week_round_trip_400_year_cycle() {
+        // 2000-01-03 is Monday of ISO week 2000-W01.
+        let mut date = Date::from_ymd(2000, 1, 3).unwrap();
+        let (mut exp_year, mut exp_week, mut exp_weekday) = (2000i64, 1u8, 1u8);
+
+        for _ in 0..146_097 {
+            let (iso_year, iso_week, weekday) = (date.iso_year(), date.iso_week(), date.weekday());
+            assert_eq!(
+                (iso_year, iso_week, weekday.number_from_monday()),
+                (exp_year, exp_week, exp_weekday),
+                "ISO week mismatch for {date}"
+            );
+            assert_eq!(
+                Date::from_iso_week(iso_year, iso_week, weekday),
+                Ok(date),
+                "round trip failed for {date}"
+            );
+
+            exp_weekday += 1;
+            if exp_weekday == 8 {
+                exp_weekday = 1;
+                exp_week += 1;
+                if exp_week > reference_iso_weeks(exp_year) {
+                    exp_week = 1;
+                    exp_year += 1;
+                }
+            }
+            date = date.succ().unwrap();
+        }
+
+        // The cycle is exactly 20_871 weeks, so we land on 2400-W01-1 again.
+        assert_eq!(date, Date::from_ymd(2400, 1, 3).unwrap());
+        assert_eq!((exp_year, exp_week, exp_weekday), (2400, 1, 1));
+        assert_eq!(reference_iso_weeks(2020), 53);
+        assert_eq!(reference_iso_weeks(2026), 53);
+        assert_eq!(reference_iso_weeks(2023), 52);
+    }
+
     /// Test 5: Negative Unix Timestamps (Pre-1970)
     /// The algorithm counts backwards from a shifted epoch.
     /// We verify this doesn't break near the Unix epoch boundary.
@@ -426,6 +3307,16 @@ mod tests {
         (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
     }
 
+    // Long ISO years, via the weekday of December 31st (Wikipedia formula).
+    fn reference_iso_weeks(year: i64) -> u8 {
+        let p = |y: i64| (y + y / 4 - y / 100 + y / 400).rem_euclid(7);
+        if p(year) == 4 || p(year - 1) == 3 {
+            53
+        } else {
+            52
+        }
+    }
+
     fn reference_days_in_month(year: i32, month: u8) -> u8 {
         match month {
             1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,